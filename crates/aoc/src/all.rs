@@ -0,0 +1,222 @@
+use std::{
+    cell::RefCell,
+    panic,
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::bail;
+use common::{DynSolution, Part};
+
+use crate::{
+    answers::Answers,
+    days,
+    input::Input,
+    output::{print_json, Format, PartResult, Status},
+    runs::Runs,
+};
+
+thread_local! {
+    /// The message of the last panic on this thread.
+    static PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Replaces the default hook, which prints every panic, with one that keeps
+/// the message so it can go in the summary.
+fn capture_panics() {
+    panic::set_hook(Box::new(|info| {
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".into());
+        let message = match info.location() {
+            Some(location) => format!("{message} at {location}"),
+            None => message,
+        };
+        PANIC.with(|panic| *panic.borrow_mut() = Some(message));
+    }));
+}
+
+/// How one part of one day went.
+enum Outcome {
+    Answered {
+        answer: String,
+        parse: Duration,
+        solve: Duration,
+    },
+    ParseFailed(String),
+    SolveFailed(String),
+    Panicked(String),
+    TimedOut,
+}
+
+/// Parses the input and solves `part` on a thread of its own, giving up on
+/// it after `timeout`. A part that times out can't be stopped, so it keeps
+/// running in the background until the runner exits.
+fn isolated(
+    solution: &'static dyn DynSolution,
+    text: String,
+    input: String,
+    part: Part,
+    timeout: Duration,
+) -> Outcome {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let attempt = || {
+            let start = Instant::now();
+            let parsed = match solution.parse(&text) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    let e = e.in_input(input);
+                    return Outcome::ParseFailed(e.to_string());
+                }
+            };
+            let parse = start.elapsed();
+            let start = Instant::now();
+            match parsed.solve(part) {
+                Ok(answer) => Outcome::Answered {
+                    answer,
+                    parse,
+                    solve: start.elapsed(),
+                },
+                Err(e) => Outcome::SolveFailed(e.message().to_owned()),
+            }
+        };
+        // Nothing the attempt touched is looked at again after a panic.
+        let attempt = panic::AssertUnwindSafe(attempt);
+        let outcome = panic::catch_unwind(attempt).unwrap_or_else(|_| {
+            let message = PANIC.with(|panic| panic.borrow_mut().take());
+            Outcome::Panicked(message.unwrap_or_default())
+        });
+        let _ = sender.send(outcome);
+    });
+    receiver.recv_timeout(timeout).unwrap_or(Outcome::TimedOut)
+}
+
+/// Runs every day with an input named `input`, each part isolated from the
+/// others, and prints a summary.
+pub fn run_all(
+    part: Option<Part>,
+    input: Input,
+    timeout: Duration,
+    format: Format,
+) -> anyhow::Result<()> {
+    if input == Input::Stdin {
+        bail!("Every day needs an input of its own, stdin can't be used.");
+    }
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let answers = Answers::load()?;
+    let record = input == Input::default();
+    let mut runs = Runs::load()?;
+    capture_panics();
+    let mut results = Vec::new();
+    for &solution in days::DAYS {
+        let day = solution.day();
+        let Ok(text) = input.read(day) else {
+            eprintln!("Skipping day {day}, no input named {input}.");
+            continue;
+        };
+        for &part in &parts {
+            let name = input.to_string();
+            let outcome =
+                isolated(solution, text.clone(), name.clone(), part, timeout);
+            let result = match outcome {
+                Outcome::Answered {
+                    answer,
+                    parse,
+                    solve,
+                } => {
+                    if record {
+                        runs.record(day, parse, part, &answer, solve);
+                    }
+                    let expected = answers.get(day, part).filter(|_| record);
+                    PartResult::answered(
+                        day, part, name, answer, parse, solve, expected,
+                    )
+                }
+                Outcome::ParseFailed(message) => PartResult::failed(
+                    day,
+                    part,
+                    name,
+                    Status::ParseError,
+                    Some(message),
+                ),
+                Outcome::SolveFailed(message) => PartResult::failed(
+                    day,
+                    part,
+                    name,
+                    Status::Failed,
+                    Some(message),
+                ),
+                Outcome::Panicked(message) => PartResult::failed(
+                    day,
+                    part,
+                    name,
+                    Status::Panicked,
+                    Some(message),
+                ),
+                Outcome::TimedOut => {
+                    PartResult::failed(day, part, name, Status::TimedOut, None)
+                }
+            };
+            eprintln!("Day {day} part {part}: {}", result.status);
+            results.push(result);
+        }
+    }
+    let _ = panic::take_hook();
+    if record {
+        runs.save()?;
+    }
+
+    match format {
+        Format::Table => print_table(&results, timeout),
+        Format::Json => print_json(&results)?,
+    }
+    let failed = results.iter().any(|result| {
+        !matches!(result.status, Status::Ok | Status::Unverified)
+    });
+    if failed {
+        bail!("Not every part ran cleanly.");
+    }
+    Ok(())
+}
+
+fn print_table(results: &[PartResult], timeout: Duration) {
+    println!();
+    println!(
+        "{:>3}  {:>4}  {:<11}  {:>10}  Answer",
+        "Day", "Part", "Status", "Time"
+    );
+    for result in results {
+        let time = match (result.time(), result.status) {
+            (Some(time), _) => format!("{time:.2?}"),
+            (None, Status::TimedOut) => format!("> {timeout:.2?}"),
+            (None, _) => "-".into(),
+        };
+        let answer = result.answer.as_deref().unwrap_or_default();
+        let mut detail = result.error.as_deref().unwrap_or(answer).lines();
+        let row = format!(
+            "{:>3}  {:>4}  {:<11}  {time:>10}  ",
+            result.day, result.part, result.status
+        );
+        println!("{row}{}", detail.next().unwrap_or_default());
+        // Parse errors point at the bad line below their message.
+        for line in detail {
+            println!("{:width$}{line}", "", width = row.len());
+        }
+    }
+    let summary: Vec<String> = Status::ALL
+        .iter()
+        .map(|&status| {
+            let count = results.iter().filter(|r| r.status == status).count();
+            format!("{count} {status}")
+        })
+        .collect();
+    println!("\n{}", summary.join(", "));
+}
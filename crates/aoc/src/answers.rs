@@ -0,0 +1,125 @@
+use std::{collections::BTreeMap, fs, io, path::PathBuf};
+
+use anyhow::Context;
+use common::{puzzle::Puzzle, Part};
+use serde::{Deserialize, Serialize};
+
+use crate::workspace;
+
+/// The accepted answer for every solved part, stored in `answers.toml` at
+/// the workspace root so that changes to a solution can be checked against
+/// what was submitted.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<u8, DayAnswers>);
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+impl DayAnswers {
+    fn part_mut(&mut self, part: Part) -> &mut Option<String> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+impl Answers {
+    pub fn path() -> PathBuf {
+        workspace::root().join("answers.toml")
+    }
+
+    /// Loads `answers.toml`, which is treated as empty if it doesn't exist.
+    pub fn load() -> anyhow::Result<Self> {
+        let path = Self::path();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                return Ok(Self::default())
+            }
+            Err(e) => {
+                return Err(e).with_context(|| {
+                    format!("Couldn't read {}", path.display())
+                })
+            }
+        };
+        toml::from_str(&text)
+            .with_context(|| format!("Couldn't parse {}", path.display()))
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let path = Self::path();
+        fs::write(&path, toml::to_string(self)?)
+            .with_context(|| format!("Couldn't write {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        let answers = self.0.get(&day)?;
+        match part {
+            Part::One => answers.part1.as_deref(),
+            Part::Two => answers.part2.as_deref(),
+        }
+    }
+
+    /// Stores `answer`, returning the answer it replaced if it was different.
+    pub fn record(
+        &mut self,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Option<String> {
+        let slot = self.0.entry(day).or_default().part_mut(part);
+        let old = slot.replace(answer.to_owned());
+        old.filter(|old| old != answer)
+    }
+
+    /// Fills in every answer recorded in a day's `puzzle.md` that isn't
+    /// stored yet, returning how many were added.
+    pub fn seed(&mut self, days: impl IntoIterator<Item = u8>) -> usize {
+        let mut added = 0;
+        for day in days {
+            let path = workspace::day_dir(day).join("puzzle.md");
+            let Ok(markdown) = fs::read_to_string(path) else {
+                continue;
+            };
+            let puzzle = Puzzle::parse(&markdown);
+            for part in Part::ALL {
+                let Some(answer) = puzzle.answer(part) else {
+                    continue;
+                };
+                if self.get(day, part).is_none() {
+                    self.record(day, part, answer);
+                    added += 1;
+                }
+            }
+        }
+        added
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part;
+
+    use super::Answers;
+
+    #[test]
+    pub fn round_trip() {
+        let mut answers = Answers::default();
+        assert_eq!(answers.record(10, Part::One, "36"), None);
+        assert_eq!(answers.record(2, Part::Two, "4"), None);
+        assert_eq!(answers.record(2, Part::Two, "4"), None);
+        assert_eq!(answers.record(2, Part::Two, "5"), Some("4".into()));
+        let text = toml::to_string(&answers).unwrap();
+        assert_eq!(text, "[2]\npart2 = \"5\"\n\n[10]\npart1 = \"36\"\n");
+        let answers: Answers = toml::from_str(&text).unwrap();
+        assert_eq!(answers.get(10, Part::One), Some("36"));
+        assert_eq!(answers.get(10, Part::Two), None);
+    }
+}
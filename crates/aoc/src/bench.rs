@@ -0,0 +1,264 @@
+use std::{
+    fs,
+    hint::black_box,
+    io,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Context};
+use clap::Args;
+use common::{DynSolution, Part};
+use serde::{Deserialize, Serialize};
+
+use crate::{config, days, input::Input, output::Format, workspace};
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// Only benchmark this day, otherwise every day is benchmarked.
+    day: Option<u8>,
+    /// How many times to parse and solve each day.
+    #[arg(long, default_value_t = 10)]
+    runs: usize,
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+    /// The baseline to compare against, defaults to
+    /// `bench-baseline.json` at the workspace root.
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+    /// Store these timings in the baseline, replacing the days that were
+    /// benchmarked.
+    #[arg(long)]
+    save: bool,
+    /// How many percent slower than the baseline a median has to be to
+    /// count as a regression.
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+/// The spread of the timings of one step over every run.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Stats {
+    median_ns: u64,
+    min_ns: u64,
+    max_ns: u64,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let ns = |d: Duration| d.as_nanos() as u64;
+        Self {
+            median_ns: ns(samples[samples.len() / 2]),
+            min_ns: ns(samples[0]),
+            max_ns: ns(samples[samples.len() - 1]),
+        }
+    }
+
+    fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DayTimings {
+    day: u8,
+    parse: Stats,
+    part1: Stats,
+    part2: Stats,
+}
+
+impl DayTimings {
+    fn steps(&self) -> [(&'static str, Stats); 3] {
+        [
+            ("parse", self.parse),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ]
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Report {
+    runs: usize,
+    days: Vec<DayTimings>,
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let out = black_box(f());
+    (out, start.elapsed())
+}
+
+/// Times `runs` parses of `text` and solves of each part. Parts that fail
+/// aren't benchmarked, since only their error path would be timed.
+fn bench_day(
+    solution: &dyn DynSolution,
+    input: &Input,
+    text: &str,
+    runs: usize,
+) -> anyhow::Result<DayTimings> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut parts = [Vec::with_capacity(runs), Vec::with_capacity(runs)];
+    for run in 0..runs {
+        let (parsed, elapsed) = time(|| solution.parse(black_box(text)));
+        let parsed = parsed.map_err(|e| e.in_input(input.to_string()))?;
+        parse.push(elapsed);
+        for (part, samples) in Part::ALL.into_iter().zip(&mut parts) {
+            let (answer, elapsed) = time(|| parsed.solve(part));
+            if let (0, Err(e)) = (run, answer) {
+                bail!("Day {} part {part} failed: {e}", solution.day());
+            }
+            samples.push(elapsed);
+        }
+    }
+    let [part1, part2] = parts.map(Stats::new);
+    Ok(DayTimings {
+        day: solution.day(),
+        parse: Stats::new(parse),
+        part1,
+        part2,
+    })
+}
+
+fn load_baseline(path: &PathBuf) -> anyhow::Result<Option<Report>> {
+    match fs::read_to_string(path) {
+        Ok(text) => serde_json::from_str(&text)
+            .map(Some)
+            .with_context(|| format!("Couldn't parse {}", path.display())),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => {
+            Err(e).with_context(|| format!("Couldn't read {}", path.display()))
+        }
+    }
+}
+
+/// How much slower `now` is than `then` in percent.
+fn change(now: Stats, then: Stats) -> f64 {
+    (now.median_ns as f64 / then.median_ns.max(1) as f64 - 1.0) * 100.0
+}
+
+fn print_table(report: &Report, baseline: Option<&Report>, threshold: f64) {
+    println!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}  {:>8}",
+        "Day", "Step", "Median", "Min", "Max", "Baseline", "Change"
+    );
+    for timings in &report.days {
+        let before =
+            baseline.and_then(|b| b.days.iter().find(|t| t.day == timings.day));
+        let before = before.map(DayTimings::steps);
+        for (i, (step, stats)) in timings.steps().into_iter().enumerate() {
+            let (then, delta) = match before {
+                Some(before) => {
+                    let then = before[i].1;
+                    let delta = change(stats, then);
+                    let flag = if delta > threshold { " !" } else { "" };
+                    (
+                        format!("{:.2?}", then.median()),
+                        format!("{delta:+.1}%{flag}"),
+                    )
+                }
+                None => ("-".into(), "-".into()),
+            };
+            println!(
+                "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}  {:>8}",
+                timings.day,
+                step,
+                format!("{:.2?}", stats.median()),
+                format!("{:.2?}", Duration::from_nanos(stats.min_ns)),
+                format!("{:.2?}", Duration::from_nanos(stats.max_ns)),
+                then,
+                delta,
+            );
+        }
+    }
+}
+
+/// Every step whose median got slower than the baseline by more than
+/// `threshold` percent.
+fn regressions(
+    report: &Report,
+    baseline: &Report,
+    threshold: f64,
+) -> Vec<String> {
+    let mut out = Vec::new();
+    for timings in &report.days {
+        let Some(before) = baseline.days.iter().find(|t| t.day == timings.day)
+        else {
+            continue;
+        };
+        for ((step, now), (_, then)) in
+            timings.steps().into_iter().zip(before.steps())
+        {
+            let delta = change(now, then);
+            if delta > threshold {
+                out.push(format!(
+                    "Day {} {step}: {:.2?} -> {:.2?} ({delta:+.1}%)",
+                    timings.day,
+                    then.median(),
+                    now.median()
+                ));
+            }
+        }
+    }
+    out
+}
+
+pub fn bench(args: BenchArgs) -> anyhow::Result<()> {
+    if args.runs == 0 {
+        bail!("There has to be at least one run.");
+    }
+    let solutions = match args.day {
+        Some(day) => vec![days::get(day)
+            .ok_or_else(|| anyhow!("Day {day} isn't solved yet."))?],
+        None => days::DAYS.to_vec(),
+    };
+    let mut report = Report {
+        runs: args.runs,
+        days: Vec::new(),
+    };
+    for solution in solutions {
+        let input = Input::default();
+        let Ok(text) = input.read(solution.day()) else {
+            eprintln!("Skipping day {}, no input.", solution.day());
+            continue;
+        };
+        let timings = bench_day(solution, &input, &text, args.runs)?;
+        report.days.push(timings);
+    }
+
+    let path = args
+        .baseline
+        .unwrap_or_else(|| workspace::root().join("bench-baseline.json"));
+    let baseline = load_baseline(&path)?;
+    match args.format.unwrap_or(config::get().runner.format) {
+        Format::Table => {
+            print_table(&report, baseline.as_ref(), args.threshold)
+        }
+        Format::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+    }
+
+    if args.save {
+        let mut saved = baseline.unwrap_or_default();
+        saved.runs = report.runs;
+        saved
+            .days
+            .retain(|t| report.days.iter().all(|r| r.day != t.day));
+        saved.days.extend(report.days.iter().cloned());
+        saved.days.sort_by_key(|t| t.day);
+        fs::write(&path, serde_json::to_string_pretty(&saved)?)
+            .with_context(|| format!("Couldn't write {}", path.display()))?;
+        eprintln!("Saved baseline to {}", path.display());
+        return Ok(());
+    }
+    let regressed = baseline
+        .map(|baseline| regressions(&report, &baseline, args.threshold))
+        .unwrap_or_default();
+    if !regressed.is_empty() {
+        for regression in &regressed {
+            eprintln!("{regression}");
+        }
+        bail!("Slower than the baseline at {}.", path.display());
+    }
+    Ok(())
+}
@@ -0,0 +1,182 @@
+use std::{
+    env, fs, io, path::PathBuf, str::FromStr, sync::OnceLock, time::Duration,
+};
+
+use anyhow::{anyhow, bail, Context};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::{output::Format, workspace};
+
+/// The file at the workspace root the settings are read from.
+pub const FILE: &str = ".aoc.toml";
+
+/// Stands for the day's number in [`Config::day_dir`].
+const DAY: &str = "{day}";
+
+/// The workspace's settings from `.aoc.toml`, where anything left out keeps
+/// its default and every setting can be overridden by an `AOC_*` variable,
+/// e.g. `AOC_YEAR=2023`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The event every day in the workspace belongs to.
+    pub year: u16,
+    /// Where the session cookie is kept, relative to the workspace root.
+    pub session_file: PathBuf,
+    /// Where each day's crate is, relative to the workspace root, with
+    /// `{day}` standing for its number.
+    pub day_dir: String,
+    /// The input days are downloaded to and run against unless `--input`
+    /// names another.
+    pub input: String,
+    pub runner: Runner,
+}
+
+/// Defaults for the runner's flags.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Runner {
+    /// How many seconds each part gets with `run --all`.
+    pub timeout: f64,
+    pub format: Format,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: 2024,
+            session_file: PathBuf::from("days/.adventofcode.session"),
+            day_dir: format!("days/day{DAY}"),
+            input: client::INPUT_FILE.into(),
+            runner: Runner::default(),
+        }
+    }
+}
+
+impl Default for Runner {
+    fn default() -> Self {
+        Self {
+            timeout: 60.0,
+            format: Format::Table,
+        }
+    }
+}
+
+/// Replaces `setting` with the value of the environment variable `var`, if
+/// it's set.
+fn set_from_env<T>(
+    var: &str,
+    setting: &mut T,
+    parse: impl FnOnce(&str) -> Option<T>,
+) -> anyhow::Result<()> {
+    let Ok(value) = env::var(var) else {
+        return Ok(());
+    };
+    *setting =
+        parse(&value).ok_or_else(|| anyhow!("{var}={value} isn't valid"))?;
+    Ok(())
+}
+
+fn parse<T: FromStr>(value: &str) -> Option<T> {
+    value.parse().ok()
+}
+
+/// A timeout of `seconds`, which has to be a finite number that isn't
+/// negative.
+pub fn timeout(seconds: f64) -> anyhow::Result<Duration> {
+    Duration::try_from_secs_f64(seconds)
+        .map_err(|_| anyhow!("A timeout of {seconds} seconds isn't valid."))
+}
+
+impl Config {
+    pub fn path() -> PathBuf {
+        workspace::root().join(FILE)
+    }
+
+    /// Reads `.aoc.toml`, which is treated as empty if it doesn't exist, and
+    /// applies the environment's overrides.
+    fn load() -> anyhow::Result<Self> {
+        let path = Self::path();
+        let mut config: Self = match fs::read_to_string(&path) {
+            Ok(text) => toml::from_str(&text).with_context(|| {
+                format!("Couldn't parse {}", path.display())
+            })?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(e) => {
+                return Err(e).with_context(|| {
+                    format!("Couldn't read {}", path.display())
+                })
+            }
+        };
+        set_from_env("AOC_YEAR", &mut config.year, parse)?;
+        set_from_env("AOC_SESSION_FILE", &mut config.session_file, parse)?;
+        set_from_env("AOC_DAY_DIR", &mut config.day_dir, parse)?;
+        set_from_env("AOC_INPUT", &mut config.input, parse)?;
+        set_from_env("AOC_TIMEOUT", &mut config.runner.timeout, parse)?;
+        set_from_env("AOC_FORMAT", &mut config.runner.format, |value| {
+            Format::from_str(value, true).ok()
+        })?;
+        if !config.day_dir.contains(DAY) {
+            bail!("day_dir is {}, which has no {DAY} in it", config.day_dir);
+        }
+        timeout(config.runner.timeout)
+            .context("runner.timeout has to be a number of seconds")?;
+        Ok(config)
+    }
+
+    /// Where day `day` is, relative to the workspace root.
+    pub fn day_path(&self, day: u8) -> String {
+        self.day_dir.replace(DAY, &day.to_string())
+    }
+
+    /// The day a path relative to the workspace root is the directory of.
+    pub fn day_of(&self, path: &str) -> Option<u8> {
+        let (prefix, suffix) = self.day_dir.split_once(DAY)?;
+        let path = path.strip_prefix(prefix)?.strip_suffix(suffix)?;
+        path.parse().ok()
+    }
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Loads the settings, which has to happen before the command line is
+/// parsed since they're its defaults.
+pub fn init() -> anyhow::Result<()> {
+    let config = Config::load()?;
+    let _ = CONFIG.set(config);
+    Ok(())
+}
+
+/// The workspace's settings, or the defaults if [`init`] hasn't loaded them,
+/// as in tests.
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{timeout, Config};
+
+    #[test]
+    pub fn day_dirs() {
+        let config = Config {
+            day_dir: "{day}/solution".into(),
+            ..Config::default()
+        };
+        assert_eq!(config.day_path(7), "7/solution");
+        assert_eq!(config.day_of("12/solution"), Some(12));
+        assert_eq!(config.day_of("12/other"), None);
+        assert_eq!(Config::default().day_of("days/day3"), Some(3));
+    }
+
+    #[test]
+    pub fn timeouts() {
+        assert_eq!(timeout(1.5).unwrap(), Duration::from_millis(1500));
+        for seconds in [-1.0, f64::NAN, f64::INFINITY] {
+            assert!(timeout(seconds).is_err());
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use common::DynSolution;
+
+pub const DAYS: &[&dyn DynSolution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+];
+
+pub fn get(number: u8) -> Option<&'static dyn DynSolution> {
+    DAYS.iter().copied().find(|day| day.day() == number)
+}
@@ -0,0 +1,23 @@
+use client::Client;
+
+use crate::{config, workspace};
+
+/// A client for the session stored in the workspace.
+pub fn connect() -> anyhow::Result<Client> {
+    Ok(Client::new(config::get().year, workspace::session()?))
+}
+
+/// Downloads whatever of a day's puzzle and input isn't cached yet.
+pub fn fetch(day: u8, refresh: bool) -> anyhow::Result<()> {
+    let dir = workspace::day_dir(day);
+    let input = &config::get().input;
+    let fetched = client::fetch(&dir, day, input, refresh, connect)?;
+    for (fetched, file) in [
+        (fetched.puzzle, client::PUZZLE_FILE),
+        (fetched.input, input),
+    ] {
+        let state = if fetched { "downloaded" } else { "cached" };
+        println!("{}: {state}", dir.join(file).display());
+    }
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use std::{
+    convert::Infallible,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{bail, Context};
+
+use crate::{config, workspace};
+
+/// Files in a day's directory that belong to the crate or its tooling rather
+/// than being puzzle inputs.
+const NOT_INPUTS: &[&str] =
+    &["Cargo.toml", "Cargo.lock", "check-p1", "check-p2"];
+const NOT_INPUT_EXTENSIONS: &[&str] = &["md", "rs", "sh", "toml"];
+
+/// Which input to run a day against: `-` for stdin, otherwise the name of a
+/// file in the day's directory or a path to any other file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Input {
+    Stdin,
+    Named(String),
+}
+
+impl Default for Input {
+    fn default() -> Self {
+        Self::Named(config::get().input.clone())
+    }
+}
+
+impl FromStr for Input {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Self::Stdin,
+            name => Self::Named(name.into()),
+        })
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Input::Stdin => write!(f, "stdin"),
+            Input::Named(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+impl Input {
+    /// Reads the input for `day`. Names are looked up in the day's directory
+    /// first and are otherwise treated as a path.
+    pub fn read(&self, day: u8) -> anyhow::Result<String> {
+        if *self == Input::Stdin {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .context("Couldn't read stdin")?;
+            return Ok(input);
+        }
+        let path = self.path(day)?;
+        fs::read_to_string(&path)
+            .with_context(|| format!("Couldn't read {}", path.display()))
+    }
+
+    /// The file a named input is in, which stdin has none of.
+    pub fn path(&self, day: u8) -> anyhow::Result<PathBuf> {
+        let Input::Named(name) = self else {
+            bail!("stdin isn't a file");
+        };
+        let named = workspace::day_dir(day).join(name);
+        let path = if named.is_file() {
+            named
+        } else {
+            PathBuf::from(name)
+        };
+        if !path.is_file() {
+            bail!(
+                "Day {day} has no input named {name} and there's no file at \
+                 that path. Run `aoc inputs {day}` to list its inputs."
+            );
+        }
+        Ok(path)
+    }
+}
+
+fn is_input(name: &str) -> bool {
+    let extension = Path::new(name).extension().and_then(|e| e.to_str());
+    !name.starts_with('.')
+        && !NOT_INPUTS.contains(&name)
+        && !extension.is_some_and(|e| NOT_INPUT_EXTENSIONS.contains(&e))
+}
+
+/// The names of every input in a day's directory, sorted.
+pub fn list(day: u8) -> anyhow::Result<Vec<String>> {
+    let dir = workspace::day_dir(day);
+    let entries = fs::read_dir(&dir)
+        .with_context(|| format!("Couldn't read {}", dir.display()))?;
+    let mut names = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        if !entry.file_type()?.is_file() {
+            continue;
+        }
+        if let Some(name) = entry.file_name().to_str() {
+            if is_input(name) {
+                names.push(name.to_owned());
+            }
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_input, Input};
+
+    #[test]
+    pub fn names() {
+        assert_eq!("-".parse(), Ok(Input::Stdin));
+        assert_eq!("i-2".parse(), Ok(Input::Named("i-2".into())));
+        assert!(is_input("test-input-2"));
+        assert!(is_input("adversarial.txt"));
+        assert!(!is_input("puzzle.md"));
+        assert!(!is_input("check-p1"));
+        assert!(!is_input("rustfmt.toml"));
+    }
+}
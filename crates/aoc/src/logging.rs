@@ -0,0 +1,46 @@
+use std::env;
+
+use clap::Args;
+use log::LevelFilter;
+
+/// Where the days' diagnostics go. They're logged with the day's crate as
+/// the target, e.g. `day6`, and always written to stderr so they never mix
+/// with the answers.
+#[derive(Args)]
+pub struct LogArgs {
+    /// Show debug output, or trace output if given twice.
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Show all trace output, same as `-vv`.
+    #[arg(long, global = true)]
+    trace: bool,
+    /// Levels per target on top of that, like `day6=trace,day8=off`. The
+    /// `AOC_LOG` environment variable takes the same syntax.
+    #[arg(long, global = true, value_name = "FILTER")]
+    log: Option<String>,
+}
+
+impl LogArgs {
+    fn level(&self) -> LevelFilter {
+        match (self.trace, self.verbose) {
+            (true, _) | (_, 2..) => LevelFilter::Trace,
+            (_, 1) => LevelFilter::Debug,
+            _ => LevelFilter::Warn,
+        }
+    }
+
+    pub fn init(&self) {
+        let mut builder = env_logger::Builder::new();
+        builder
+            .filter_level(self.level())
+            .format_timestamp(None)
+            .target(env_logger::Target::Stderr);
+        if let Ok(filter) = env::var("AOC_LOG") {
+            builder.parse_filters(&filter);
+        }
+        if let Some(filter) = &self.log {
+            builder.parse_filters(filter);
+        }
+        builder.init();
+    }
+}
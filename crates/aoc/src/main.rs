@@ -0,0 +1,410 @@
+mod all;
+mod answers;
+mod bench;
+mod config;
+mod days;
+mod fetch;
+mod input;
+mod logging;
+mod output;
+mod runs;
+mod scaffold;
+mod show;
+mod status;
+mod submit;
+mod watch;
+mod workspace;
+
+use std::time::Instant;
+
+use answers::Answers;
+use anyhow::{anyhow, bail};
+use bench::BenchArgs;
+use clap::{Parser, Subcommand};
+use common::{ParseError, Part};
+use input::Input;
+use logging::LogArgs;
+use output::{print_json, Format, PartResult, Status};
+use runs::Runs;
+use show::ShowArgs;
+use submit::SubmitArgs;
+use watch::WatchArgs;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code solutions.")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    #[command(flatten)]
+    log: LogArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs one day's solutions against its input, or every day's.
+    Run {
+        /// The day to run.
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        /// Run every day, each part on its own thread, and summarize which
+        /// answered, panicked or timed out.
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+        /// How many seconds each part gets with `--all`, 60 unless
+        /// `.aoc.toml` says otherwise.
+        #[arg(long, conflicts_with = "day")]
+        timeout: Option<f64>,
+        /// Only run this part, otherwise both parts are run.
+        #[arg(long)]
+        part: Option<Part>,
+        /// The name of an input in the day's directory, a path to one, or
+        /// `-` to read it from stdin.
+        #[arg(long, default_value_t)]
+        input: Input,
+        #[arg(long, value_enum)]
+        format: Option<Format>,
+        /// Print the day's breakdown of how it got its answers first, for
+        /// days that have one.
+        #[arg(long, conflicts_with_all = ["all", "format"])]
+        report: bool,
+    },
+    /// Shows a calendar of every day's stars, which parts are implemented,
+    /// and how the last run compared with the stored answers.
+    Status,
+    /// Shows a day's puzzle with its stored answers.
+    Show(ShowArgs),
+    /// Rebuilds and re-runs a day whenever its source or input changes.
+    Watch(WatchArgs),
+    /// Lists the inputs in each day's directory.
+    Inputs {
+        /// Only list this day's inputs.
+        day: Option<u8>,
+    },
+    /// Re-runs every day, or just one, and compares the results with the
+    /// answers stored in `answers.toml`.
+    Verify {
+        /// Only verify this day.
+        day: Option<u8>,
+        #[arg(long, value_enum)]
+        format: Option<Format>,
+    },
+    /// Times parsing and each part over repeated runs and compares the
+    /// medians with a saved baseline.
+    Bench(BenchArgs),
+    /// Creates a day from `days/template` and adds it to the workspace and
+    /// the runner. Anything that already exists is left alone.
+    New { day: u8 },
+    /// Downloads a day's puzzle and input unless they're already cached.
+    Fetch {
+        day: u8,
+        /// Download the puzzle again, e.g. to get part two.
+        #[arg(long)]
+        refresh: bool,
+    },
+    /// Submits an answer and stores it in `answers.toml` once it's accepted.
+    Submit(SubmitArgs),
+    /// Prints the settings from `.aoc.toml` and the environment, or just
+    /// the value of one of them, e.g. `session_file`.
+    Config { setting: Option<String> },
+    /// Shows the guesses submitted for a day.
+    Guesses { day: u8 },
+    /// Manages the accepted answers in `answers.toml`.
+    #[command(subcommand)]
+    Answers(AnswersCommand),
+}
+
+#[derive(Subcommand)]
+enum AnswersCommand {
+    /// Adds every answer recorded in the days' `puzzle.md` that isn't stored
+    /// yet.
+    Seed,
+    /// Stores an accepted answer, replacing the stored one.
+    Record { day: u8, part: Part, answer: String },
+}
+
+/// The result of a part whose input couldn't be parsed.
+fn parse_failed(
+    day: u8,
+    part: Part,
+    input: &Input,
+    error: &ParseError,
+) -> PartResult {
+    let error = Some(error.to_string());
+    PartResult::failed(day, part, input.to_string(), Status::ParseError, error)
+}
+
+fn run(
+    day: u8,
+    part: Option<Part>,
+    input: Input,
+    format: Format,
+    report: bool,
+) -> anyhow::Result<()> {
+    let solution =
+        days::get(day).ok_or_else(|| anyhow!("Day {day} isn't solved yet."))?;
+    let text = input.read(day)?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let start = Instant::now();
+    let parsed = match solution.parse(&text) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            let e = e.in_input(input.to_string());
+            if format == Format::Table {
+                return Err(e.into());
+            }
+            let results: Vec<PartResult> = parts
+                .into_iter()
+                .map(|part| parse_failed(day, part, &input, &e))
+                .collect();
+            print_json(&results)?;
+            bail!("Day {day} couldn't be parsed.");
+        }
+    };
+    let parse_time = start.elapsed();
+    if report {
+        let report = parsed
+            .report()
+            .ok_or_else(|| anyhow!("Day {day} has no report."))?;
+        println!("{report}");
+    }
+    // Only runs against the real input say anything about the answers.
+    let record = input == Input::default();
+    let answers = Answers::load()?;
+    let mut runs = Runs::load()?;
+    let mut results = Vec::new();
+    for part in parts {
+        let start = Instant::now();
+        let answer = parsed.solve(part);
+        let solve_time = start.elapsed();
+        let answer = match answer {
+            Ok(answer) => answer,
+            Err(e) => {
+                if format == Format::Table {
+                    println!("Part {part} failed: {e}");
+                }
+                let error = Some(e.to_string());
+                let name = input.to_string();
+                let status = Status::Failed;
+                results
+                    .push(PartResult::failed(day, part, name, status, error));
+                continue;
+            }
+        };
+        if format == Format::Table {
+            println!("Part {part}: {answer}");
+        }
+        if record {
+            runs.record(day, parse_time, part, &answer, solve_time);
+        }
+        let expected = answers.get(day, part).filter(|_| record);
+        results.push(PartResult::answered(
+            day,
+            part,
+            input.to_string(),
+            answer,
+            parse_time,
+            solve_time,
+            expected,
+        ));
+    }
+    if record {
+        runs.save()?;
+    }
+    if format == Format::Json {
+        print_json(&results)?;
+    }
+    if results.iter().any(|result| result.status == Status::Failed) {
+        bail!("Not every part could be solved.");
+    }
+    Ok(())
+}
+
+fn verify(day: Option<u8>, format: Format) -> anyhow::Result<()> {
+    let answers = Answers::load()?;
+    let solutions = match day {
+        Some(day) => vec![days::get(day)
+            .ok_or_else(|| anyhow!("Day {day} isn't solved yet."))?],
+        None => days::DAYS.to_vec(),
+    };
+    let table = format == Format::Table;
+    let mut runs = Runs::load()?;
+    let mut results = Vec::new();
+    for solution in solutions {
+        let day = solution.day();
+        let expected = Part::ALL.map(|part| answers.get(day, part));
+        if expected.iter().all(Option::is_none) {
+            if table {
+                println!("Day {day}: no stored answers");
+            }
+            continue;
+        }
+        let input = Input::default();
+        let text = input.read(day)?;
+        let start = Instant::now();
+        let parsed = match solution.parse(&text) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                let e = e.in_input(input.to_string());
+                if table {
+                    println!("Day {day}: parse error, {e}");
+                }
+                for (part, expected) in Part::ALL.into_iter().zip(expected) {
+                    if expected.is_some() {
+                        results.push(parse_failed(day, part, &input, &e));
+                    }
+                }
+                continue;
+            }
+        };
+        let parse_time = start.elapsed();
+        for (part, expected) in Part::ALL.into_iter().zip(expected) {
+            let Some(expected) = expected else {
+                if table {
+                    println!("Day {day} part {part}: no stored answer");
+                }
+                continue;
+            };
+            let start = Instant::now();
+            let answer = parsed.solve(part);
+            let solve_time = start.elapsed();
+            let answer = match answer {
+                Ok(answer) => answer,
+                Err(e) => {
+                    if table {
+                        println!("Day {day} part {part}: failed, {e}");
+                    }
+                    results.push(PartResult::failed(
+                        day,
+                        part,
+                        input.to_string(),
+                        Status::Failed,
+                        Some(e.to_string()),
+                    ));
+                    continue;
+                }
+            };
+            runs.record(day, parse_time, part, &answer, solve_time);
+            if table && answer == expected {
+                println!("Day {day} part {part}: ok");
+            } else if table {
+                println!(
+                    "Day {day} part {part}: got {answer}, expected {expected}"
+                );
+            }
+            results.push(PartResult::answered(
+                day,
+                part,
+                input.to_string(),
+                answer,
+                parse_time,
+                solve_time,
+                Some(expected),
+            ));
+        }
+    }
+    runs.save()?;
+    if !table {
+        print_json(&results)?;
+    }
+    let mismatched = results.iter().any(|result| {
+        matches!(
+            result.status,
+            Status::Wrong | Status::Failed | Status::ParseError
+        )
+    });
+    if mismatched {
+        bail!("Some answers don't match {}", Answers::path().display());
+    }
+    Ok(())
+}
+
+fn inputs(day: Option<u8>) -> anyhow::Result<()> {
+    let days: Vec<u8> = match day {
+        Some(day) => vec![day],
+        None => days::DAYS.iter().map(|day| day.day()).collect(),
+    };
+    for day in days {
+        println!("Day {day}: {}", input::list(day)?.join(", "));
+    }
+    Ok(())
+}
+
+fn answers(command: AnswersCommand) -> anyhow::Result<()> {
+    let mut answers = Answers::load()?;
+    match command {
+        AnswersCommand::Seed => {
+            let added = answers.seed(1..=25);
+            println!("Added {added} answers.");
+        }
+        AnswersCommand::Record { day, part, answer } => {
+            if let Some(old) = answers.record(day, part, &answer) {
+                println!("Day {day} part {part}: replaced {old} with {answer}");
+            }
+        }
+    }
+    answers.save()
+}
+
+fn show_config(setting: Option<String>) -> anyhow::Result<()> {
+    let Some(setting) = setting else {
+        print!("{}", toml::to_string(config::get())?);
+        return Ok(());
+    };
+    let config = toml::Value::try_from(config::get())?;
+    let value = setting
+        .split('.')
+        .try_fold(&config, |value, key| value.get(key))
+        .ok_or_else(|| anyhow!("There's no setting called {setting}."))?;
+    match value {
+        toml::Value::String(value) => println!("{value}"),
+        value => println!("{value}"),
+    }
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    config::init()?;
+    let cli = Cli::parse();
+    cli.log.init();
+    let runner = &config::get().runner;
+    match cli.command {
+        Command::Run {
+            day: Some(day),
+            part,
+            input,
+            format,
+            report,
+            ..
+        } => {
+            let format = format.unwrap_or(runner.format);
+            run(day, part, input, format, report)
+        }
+        Command::Run {
+            part,
+            input,
+            timeout,
+            format,
+            ..
+        } => {
+            let timeout = config::timeout(timeout.unwrap_or(runner.timeout))?;
+            all::run_all(part, input, timeout, format.unwrap_or(runner.format))
+        }
+        Command::Status => status::status(),
+        Command::Show(args) => show::show(args),
+        Command::Watch(args) => watch::watch(args),
+        Command::Inputs { day } => inputs(day),
+        Command::Verify { day, format } => {
+            verify(day, format.unwrap_or(runner.format))
+        }
+        Command::Bench(args) => bench::bench(args),
+        Command::New { day } => scaffold::new_day(day),
+        Command::Fetch { day, refresh } => fetch::fetch(day, refresh),
+        Command::Submit(args) => submit::submit(args),
+        Command::Config { setting } => show_config(setting),
+        Command::Guesses { day } => submit::guesses(day),
+        Command::Answers(command) => answers(command),
+    }
+}
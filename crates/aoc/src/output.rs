@@ -0,0 +1,132 @@
+use std::{fmt::Display, time::Duration};
+
+use clap::ValueEnum;
+use common::Part;
+use serde::{Deserialize, Serialize};
+
+/// How a command prints its results: readable text, or JSON for scripts.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    Table,
+    Json,
+}
+
+/// How an answer compares with the stored one, or why there isn't one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Status {
+    Ok,
+    Wrong,
+    /// There's no stored answer to compare with.
+    Unverified,
+    ParseError,
+    /// The part reported that it couldn't work out an answer.
+    Failed,
+    Panicked,
+    TimedOut,
+}
+
+impl Status {
+    pub const ALL: [Status; 7] = [
+        Status::Ok,
+        Status::Wrong,
+        Status::Unverified,
+        Status::ParseError,
+        Status::Failed,
+        Status::Panicked,
+        Status::TimedOut,
+    ];
+
+    pub fn check(answer: &str, expected: Option<&str>) -> Self {
+        match expected {
+            Some(expected) if expected == answer => Status::Ok,
+            Some(_) => Status::Wrong,
+            None => Status::Unverified,
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            Status::Ok => "ok",
+            Status::Wrong => "wrong",
+            Status::Unverified => "unverified",
+            Status::ParseError => "parse error",
+            Status::Failed => "failed",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed out",
+        };
+        f.pad(status)
+    }
+}
+
+/// One part run against one input.
+#[derive(Debug, Serialize)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    pub status: Status,
+    /// What went wrong, for parse errors, failures and panics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl PartResult {
+    pub fn answered(
+        day: u8,
+        part: Part,
+        input: String,
+        answer: String,
+        parse: Duration,
+        solve: Duration,
+        expected: Option<&str>,
+    ) -> Self {
+        Self {
+            day,
+            part: part.number(),
+            input,
+            status: Status::check(&answer, expected),
+            answer: Some(answer),
+            parse_ns: Some(parse.as_nanos() as u64),
+            solve_ns: Some(solve.as_nanos() as u64),
+            error: None,
+        }
+    }
+
+    pub fn failed(
+        day: u8,
+        part: Part,
+        input: String,
+        status: Status,
+        error: Option<String>,
+    ) -> Self {
+        Self {
+            day,
+            part: part.number(),
+            input,
+            answer: None,
+            parse_ns: None,
+            solve_ns: None,
+            status,
+            error,
+        }
+    }
+
+    /// How long parsing and solving took together.
+    pub fn time(&self) -> Option<Duration> {
+        Some(Duration::from_nanos(self.parse_ns? + self.solve_ns?))
+    }
+}
+
+pub fn print_json(results: &[PartResult]) -> anyhow::Result<()> {
+    println!("{}", serde_json::to_string_pretty(results)?);
+    Ok(())
+}
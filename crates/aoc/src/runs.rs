@@ -0,0 +1,98 @@
+use std::{collections::BTreeMap, fs, io, path::PathBuf, time::Duration};
+
+use anyhow::Context;
+use common::Part;
+use serde::{Deserialize, Serialize};
+
+use crate::workspace;
+
+/// The latest answer and timing of every part run against its day's
+/// default input, kept in `last-run.json` at the workspace root.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Runs(BTreeMap<u8, DayRun>);
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct DayRun {
+    parse_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<PartRun>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<PartRun>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PartRun {
+    pub answer: String,
+    solve_ns: u64,
+}
+
+impl PartRun {
+    pub fn solve_time(&self) -> Duration {
+        Duration::from_nanos(self.solve_ns)
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos() as u64
+}
+
+impl Runs {
+    fn path() -> PathBuf {
+        workspace::root().join("last-run.json")
+    }
+
+    /// Loads `last-run.json`, which is treated as empty if it doesn't exist.
+    pub fn load() -> anyhow::Result<Self> {
+        let path = Self::path();
+        match fs::read_to_string(&path) {
+            Ok(text) => serde_json::from_str(&text)
+                .with_context(|| format!("Couldn't parse {}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                Ok(Self::default())
+            }
+            Err(e) => Err(e)
+                .with_context(|| format!("Couldn't read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let path = Self::path();
+        fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Couldn't write {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&PartRun> {
+        let run = self.0.get(&day)?;
+        match part {
+            Part::One => run.part1.as_ref(),
+            Part::Two => run.part2.as_ref(),
+        }
+    }
+
+    pub fn parse_time(&self, day: u8) -> Option<Duration> {
+        self.0
+            .get(&day)
+            .map(|run| Duration::from_nanos(run.parse_ns))
+    }
+
+    pub fn record(
+        &mut self,
+        day: u8,
+        parse: Duration,
+        part: Part,
+        answer: &str,
+        solve: Duration,
+    ) {
+        let run = self.0.entry(day).or_default();
+        run.parse_ns = nanos(parse);
+        let slot = match part {
+            Part::One => &mut run.part1,
+            Part::Two => &mut run.part2,
+        };
+        *slot = Some(PartRun {
+            answer: answer.to_owned(),
+            solve_ns: nanos(solve),
+        });
+    }
+}
@@ -0,0 +1,207 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+
+use crate::{config, fetch, workspace};
+
+/// Template files are written without this suffix, with `{{day}}`,
+/// `{{year}}`, `{{project-name}}` and `{{root}}`, the way back up to the
+/// workspace root, filled in.
+const TEMPLATE_SUFFIX: &str = ".liquid";
+const EXECUTABLES: &[&str] = &["check-p1", "check-p2"];
+
+/// Every file in the template, relative to it.
+fn template_files(dir: &Path, prefix: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let entries = fs::read_dir(dir)
+        .with_context(|| format!("Couldn't read {}", dir.display()))?;
+    for entry in entries {
+        let entry = entry?;
+        let relative = prefix.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            files.extend(template_files(&entry.path(), &relative)?);
+        } else {
+            files.push(relative);
+        }
+    }
+    Ok(files)
+}
+
+fn render(template: &str, day: u8) -> String {
+    let depth = Path::new(&config::get().day_path(day)).components().count();
+    let root = vec![".."; depth].join("/");
+    template
+        .replace("{{project-name}}", &format!("day{day}"))
+        .replace("{{day}}", &day.to_string())
+        .replace("{{year}}", &config::get().year.to_string())
+        .replace("{{root}}", &root)
+}
+
+/// Writes the template files the day doesn't have yet.
+fn create_crate(day: u8) -> anyhow::Result<()> {
+    let template = workspace::root().join("days").join("template");
+    let dir = workspace::day_dir(day);
+    for file in template_files(&template, Path::new(""))? {
+        let name = file.to_string_lossy();
+        let target = dir.join(name.trim_end_matches(TEMPLATE_SUFFIX));
+        if target.exists() {
+            continue;
+        }
+        let text = fs::read_to_string(template.join(&file))?;
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&target, render(&text, day))
+            .with_context(|| format!("Couldn't write {}", target.display()))?;
+        #[cfg(unix)]
+        if EXECUTABLES.iter().any(|name| target.ends_with(name)) {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&target, fs::Permissions::from_mode(0o755))?;
+        }
+        println!("Created {}", target.display());
+    }
+    Ok(())
+}
+
+/// Adds `line` to the lines of `text` that `day_of` finds a day in, keeping
+/// them in order, or returns `None` if `day` is already listed.
+fn insert_sorted(
+    text: &str,
+    day: u8,
+    line: &str,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> Option<String> {
+    let mut lines: Vec<String> = text.lines().map(str::to_owned).collect();
+    let listed: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_of(line)?)))
+        .collect();
+    if listed.iter().any(|&(_, listed)| listed == day) {
+        return None;
+    }
+    let at = match listed.iter().rev().find(|&&(_, listed)| listed < day) {
+        Some(&(i, _)) => {
+            // Lists without a trailing comma need one before the new entry.
+            if line.ends_with(',') && !lines[i].ends_with(',') {
+                lines[i].push(',');
+            }
+            i + 1
+        }
+        None => listed.first()?.0,
+    };
+    lines.insert(at, line.to_owned());
+    let mut out = lines.join("\n");
+    if text.ends_with('\n') {
+        out.push('\n');
+    }
+    Some(out)
+}
+
+/// Applies `insert_sorted` to a file, failing if it has no list of days.
+fn add_to(
+    path: &Path,
+    day: u8,
+    line: &str,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> anyhow::Result<()> {
+    let text = fs::read_to_string(path)
+        .with_context(|| format!("Couldn't read {}", path.display()))?;
+    if text.lines().all(|line| day_of(line).is_none()) {
+        bail!("Couldn't find where to add day {day} to {}", path.display());
+    }
+    let Some(text) = insert_sorted(&text, day, line, day_of) else {
+        return Ok(());
+    };
+    fs::write(path, text)
+        .with_context(|| format!("Couldn't write {}", path.display()))?;
+    println!("Added day {day} to {}", path.display());
+    Ok(())
+}
+
+fn member_day(line: &str) -> Option<u8> {
+    let member = line.trim().trim_end_matches(',').trim_matches('"');
+    config::get().day_of(member)
+}
+
+fn dependency_day(line: &str) -> Option<u8> {
+    let (name, _) = line.split_once(" = ")?;
+    name.strip_prefix("day")?.parse().ok()
+}
+
+fn registry_day(line: &str) -> Option<u8> {
+    let (name, _) = line.trim().strip_prefix("&day")?.split_once("::")?;
+    name.parse().ok()
+}
+
+/// Creates day `day` from `days/template`, adds it to the workspace and the
+/// runner, and downloads its puzzle if there's a session. Anything that's
+/// already there is left alone, so it's safe to run again.
+pub fn new_day(day: u8) -> anyhow::Result<()> {
+    if !(1..=25).contains(&day) {
+        bail!("There's no day {day}, days go from 1 to 25.");
+    }
+    create_crate(day)?;
+    let root = workspace::root();
+    let path = config::get().day_path(day);
+    add_to(
+        &root.join("Cargo.toml"),
+        day,
+        &format!("    \"{path}\","),
+        member_day,
+    )?;
+    let runner = root.join("crates").join("aoc");
+    add_to(
+        &runner.join("Cargo.toml"),
+        day,
+        &format!("day{day} = {{ path = \"../../{path}\" }}"),
+        dependency_day,
+    )?;
+    add_to(
+        &runner.join("src").join("days.rs"),
+        day,
+        &format!("    &day{day}::Day{day},"),
+        registry_day,
+    )?;
+    if workspace::session_file().is_file() {
+        fetch::fetch(day, false)?;
+    } else {
+        println!("No session saved, run `aoc fetch {day}` once there is.");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{insert_sorted, member_day, registry_day};
+
+    #[test]
+    pub fn insert() {
+        let members =
+            "members = [\n    \"crates/aoc\",\n    \"days/day2\",\n    \
+                       \"days/day10\"\n]\n";
+        let line = "    \"days/day11\",";
+        assert_eq!(
+            insert_sorted(members, 11, line, member_day).unwrap(),
+            "members = [\n    \"crates/aoc\",\n    \"days/day2\",\n    \
+             \"days/day10\",\n    \"days/day11\",\n]\n"
+        );
+        let line = "    \"days/day1\",";
+        assert_eq!(
+            insert_sorted(members, 1, line, member_day).unwrap(),
+            "members = [\n    \"crates/aoc\",\n    \"days/day1\",\n    \
+             \"days/day2\",\n    \"days/day10\"\n]\n"
+        );
+        assert_eq!(insert_sorted(members, 10, line, member_day), None);
+
+        let days = "&[\n    &day9::Day9,\n    &day12::Day12,\n];";
+        assert_eq!(
+            insert_sorted(days, 10, "    &day10::Day10,", registry_day)
+                .unwrap(),
+            "&[\n    &day9::Day9,\n    &day10::Day10,\n    &day12::Day12,\n];"
+        );
+    }
+}
@@ -0,0 +1,272 @@
+use std::{
+    env, fs,
+    io::{self, IsTerminal},
+};
+
+use anyhow::{anyhow, Context};
+use clap::Args;
+use common::{
+    puzzle::{Puzzle, ANSWER, PART_TWO},
+    Part,
+};
+
+use crate::{answers::Answers, workspace};
+
+/// Where the page stops describing the puzzle.
+const FOOTERS: &[&str] = &[
+    "Both parts of this puzzle are complete!",
+    "The first half of this puzzle is complete!",
+    "To begin, [get your puzzle input]",
+    "Answer:",
+];
+
+#[derive(Args)]
+pub struct ShowArgs {
+    day: u8,
+    /// Only show this part.
+    #[arg(long)]
+    part: Option<Part>,
+    /// Only list the example blocks with their indices.
+    #[arg(long)]
+    examples: bool,
+    /// Only print the example block with this index, unstyled, e.g. to pipe
+    /// it into `aoc run --input -`.
+    #[arg(long, conflicts_with = "examples")]
+    example: Option<usize>,
+}
+
+const RESET: &str = "\x1b[0m";
+const HEADING: &str = "\x1b[1;33m";
+const CODE: &str = "\x1b[36m";
+const CODE_EM: &str = "\x1b[1;96m";
+const EM: &str = "\x1b[1;97m";
+const LINK: &str = "\x1b[4m";
+const DIM: &str = "\x1b[2m";
+const ANSWERED: &str = "\x1b[1;32m";
+
+/// Wraps text in ANSI codes, or doesn't when they'd end up in a pipe.
+#[derive(Clone, Copy)]
+struct Style {
+    color: bool,
+}
+
+impl Style {
+    fn detect() -> Self {
+        let color =
+            io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none();
+        Self { color }
+    }
+
+    fn paint(self, code: &str, text: &str) -> String {
+        if self.color {
+            format!("{code}{text}{RESET}")
+        } else {
+            text.to_owned()
+        }
+    }
+
+    fn code(self, code: &'static str) -> &'static str {
+        if self.color {
+            code
+        } else {
+            ""
+        }
+    }
+}
+
+/// Renders the inline markdown of one line: code spans, emphasis, escapes
+/// and links, which are made absolute.
+fn inline(line: &str, style: Style) -> String {
+    let mut out = String::new();
+    let mut em = false;
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        rest = &rest[c.len_utf8()..];
+        match c {
+            '\\' if !rest.is_empty() => {
+                let escaped = rest.chars().next().unwrap_or('\\');
+                out.push(escaped);
+                rest = &rest[escaped.len_utf8()..];
+            }
+            '`' if rest.contains('`') => {
+                let (span, after) = rest.split_once('`').unwrap_or((rest, ""));
+                rest = after;
+                let emphasized = span
+                    .strip_prefix('*')
+                    .and_then(|span| span.strip_suffix('*'))
+                    .filter(|span| !span.is_empty());
+                out.push_str(&match emphasized {
+                    Some(span) => style.paint(CODE_EM, span),
+                    None => style.paint(CODE, span),
+                });
+                if em {
+                    out.push_str(style.code(EM));
+                }
+            }
+            '*' => {
+                em = !em;
+                out.push_str(style.code(if em { EM } else { RESET }));
+            }
+            '[' => {
+                // Only a `]` right before `(` closes a link.
+                let link = rest.split_once(']').and_then(|(text, after)| {
+                    let after = after.strip_prefix('(')?;
+                    let (url, after) = after.split_once(')')?;
+                    Some((text, url, after))
+                });
+                let Some((text, url, after)) = link else {
+                    out.push(c);
+                    continue;
+                };
+                rest = after;
+                let url = match url.starts_with('/') {
+                    true => format!("{}{url}", client::BASE_URL),
+                    false => url.to_owned(),
+                };
+                out.push_str(&style.paint(LINK, &inline(text, style)));
+                out.push_str(&style.paint(DIM, &format!(" ({url})")));
+                if em {
+                    out.push_str(style.code(EM));
+                }
+            }
+            c => out.push(c),
+        }
+    }
+    if em {
+        out.push_str(style.code(RESET));
+    }
+    out
+}
+
+/// Renders one part of the puzzle, leaving out the answer it records and
+/// everything after the description.
+fn render(markdown: &str, style: Style) -> String {
+    let mut out = String::new();
+    let mut in_block = false;
+    let mut lines = markdown.lines().peekable();
+    while let Some(line) = lines.next() {
+        if line.trim_end() == "```" {
+            in_block = !in_block;
+            continue;
+        }
+        if in_block && line.is_empty() {
+            out.push('\n');
+            continue;
+        }
+        if in_block {
+            out.push_str(&format!("    {}\n", style.paint(CODE, line)));
+            continue;
+        }
+        if FOOTERS.iter().any(|footer| line.starts_with(footer)) {
+            break;
+        }
+        if line.starts_with(ANSWER) {
+            continue;
+        }
+        // Headings start with `#`, or are underlined the way the fetched
+        // puzzles' are.
+        let underlined =
+            lines.peek().is_some_and(|next| next.starts_with("---"));
+        if line.starts_with('#') || underlined {
+            if underlined {
+                lines.next();
+            }
+            let heading = inline(line.trim_start_matches('#').trim(), style);
+            out.push_str(&format!("{}\n", style.paint(HEADING, &heading)));
+            continue;
+        }
+        match line.strip_prefix("* ") {
+            Some(item) => {
+                out.push_str(&format!("  • {}\n", inline(item, style)))
+            }
+            None => out.push_str(&format!("{}\n", inline(line, style))),
+        }
+    }
+    // Collapse the blank lines left where the answer was.
+    let mut out = out.trim_end().to_owned();
+    while out.contains("\n\n\n") {
+        out = out.replace("\n\n\n", "\n\n");
+    }
+    out
+}
+
+fn examples(puzzle: &Puzzle, style: Style) {
+    for (i, block) in puzzle.blocks().enumerate() {
+        println!("{}", style.paint(HEADING, &format!("[{i}]")));
+        print!("{}", style.paint(CODE, block));
+        println!();
+    }
+}
+
+pub fn show(args: ShowArgs) -> anyhow::Result<()> {
+    let path = workspace::day_dir(args.day).join(client::PUZZLE_FILE);
+    let markdown = fs::read_to_string(&path).with_context(|| {
+        format!(
+            "Couldn't read {}, run `aoc fetch {}` first",
+            path.display(),
+            args.day
+        )
+    })?;
+    let puzzle = Puzzle::parse(&markdown);
+    let style = Style::detect();
+    if let Some(i) = args.example {
+        let block = puzzle.blocks().nth(i).ok_or_else(|| {
+            anyhow!("Day {} has no example block {i}.", args.day)
+        })?;
+        print!("{block}");
+        return Ok(());
+    }
+    if args.examples {
+        examples(&puzzle, style);
+        return Ok(());
+    }
+
+    let answers = Answers::load()?;
+    let sections = match markdown.split_once(PART_TWO) {
+        Some((one, two)) => vec![one.to_owned(), format!("{PART_TWO}{two}")],
+        None => vec![markdown],
+    };
+    for (part, section) in Part::ALL.into_iter().zip(&sections) {
+        if args.part.is_some_and(|only| only != part) {
+            continue;
+        }
+        println!("{}\n", render(section, style));
+        let answer = answers.get(args.day, part).or(puzzle.answer(part));
+        if let Some(answer) = answer {
+            let answer = style.paint(ANSWERED, answer);
+            println!("Your puzzle answer was {answer}.\n");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{inline, render, Style};
+
+    #[test]
+    pub fn inline_markdown() {
+        let line = "see [1518](/2018/day/5), \\*\\* and *total `*11*`*";
+        let plain = Style { color: false };
+        assert_eq!(
+            inline(line, plain),
+            "see 1518 (https://adventofcode.com/2018/day/5), ** and total 11"
+        );
+        let color = Style { color: true };
+        assert_eq!(
+            inline("*a `b` c*", color),
+            "\x1b[1;97ma \x1b[36mb\x1b[0m\x1b[1;97m c\x1b[0m"
+        );
+        assert_eq!(
+            inline("[a] and [b](/c)", plain),
+            "[a] and b (https://adventofcode.com/c)"
+        );
+    }
+
+    #[test]
+    pub fn headings() {
+        let plain = Style { color: false };
+        let markdown = "\\--- Day 1 ---\n----------\n\n## Notes\n\\*not\\*\n";
+        assert_eq!(render(markdown, plain), "--- Day 1 ---\n\nNotes\n*not*");
+    }
+}
@@ -0,0 +1,111 @@
+use std::{fs, panic};
+
+use common::{puzzle::Puzzle, DynSolution, Part};
+
+use crate::{answers::Answers, days, runs::Runs, workspace};
+
+/// What `part` answers on an empty input, which tells a day fresh from the
+/// template apart before it has an example or a run. A day that panics on
+/// it answers something else than `todo` at least.
+fn on_empty(solution: &dyn DynSolution, part: Part) -> Option<String> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let answer = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        solution.parse("").ok()?.solve(part).ok()
+    }));
+    panic::set_hook(hook);
+    answer.unwrap_or_else(|_| Some(String::new()))
+}
+
+/// Whether a part answers anything yet. Parts still answering `todo` on
+/// the example don't, and without an example the last run decides, or what
+/// it answers on an empty input when it hasn't been run.
+fn implemented(
+    solution: &dyn DynSolution,
+    puzzle: &Puzzle,
+    runs: &Runs,
+    part: Part,
+) -> Option<bool> {
+    let answer = match puzzle.example(part) {
+        // A part that fails on the example still answers something.
+        Some(example) => solution
+            .parse(example)
+            .ok()?
+            .solve(part)
+            .unwrap_or_default(),
+        None => match runs.get(solution.day(), part) {
+            Some(run) => run.answer.clone(),
+            None => on_empty(solution, part)?,
+        },
+    };
+    Some(answer != "todo")
+}
+
+/// How the part compares with its stored answer.
+fn state(
+    day: u8,
+    part: Part,
+    implemented: Option<bool>,
+    answer: Option<&str>,
+    runs: &Runs,
+) -> String {
+    if implemented == Some(false) {
+        return "todo".into();
+    }
+    let Some(run) = runs.get(day, part) else {
+        // `aoc verify` only runs parts with a stored answer, and a part
+        // that couldn't be told apart from the template is taken to be one.
+        return match (answer, implemented) {
+            (Some(_), _) => "not run".into(),
+            (None, Some(true)) => "no answer".into(),
+            (None, _) => "todo".into(),
+        };
+    };
+    match answer {
+        Some(answer) if answer == run.answer => "ok".into(),
+        Some(_) => "wrong".into(),
+        None => "unverified".into(),
+    }
+}
+
+pub fn status() -> anyhow::Result<()> {
+    let answers = Answers::load()?;
+    let runs = Runs::load()?;
+    println!(
+        "{:>3}  {:<5}  {:<10}  {:>10}  {:<10}  {:>10}",
+        "Day", "Stars", "Part 1", "Time", "Part 2", "Time"
+    );
+    for day in 1..=25 {
+        let dir = workspace::day_dir(day);
+        let Some(solution) = days::get(day) else {
+            if dir.is_dir() {
+                println!("{day:>3}  {:<5}  not registered", "");
+            } else {
+                println!("{day:>3}");
+            }
+            continue;
+        };
+        let markdown = fs::read_to_string(dir.join(client::PUZZLE_FILE));
+        let puzzle = Puzzle::parse(&markdown.unwrap_or_default());
+        let mut stars = String::new();
+        let mut columns = Vec::new();
+        for part in Part::ALL {
+            let answer = answers.get(day, part).or(puzzle.answer(part));
+            if answer.is_some() {
+                stars.push('*');
+            }
+            let implemented = implemented(solution, &puzzle, &runs, part);
+            columns.push(state(day, part, implemented, answer, &runs));
+            // The time it took to parse the input and solve the part.
+            let parse = runs.parse_time(day).unwrap_or_default();
+            columns.push(runs.get(day, part).map_or("-".into(), |run| {
+                format!("{:.2?}", parse + run.solve_time())
+            }));
+        }
+        println!(
+            "{day:>3}  {stars:<5}  {:<10}  {:>10}  {:<10}  {:>10}",
+            columns[0], columns[1], columns[2], columns[3]
+        );
+    }
+    Ok(())
+}
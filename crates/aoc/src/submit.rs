@@ -0,0 +1,105 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Context};
+use clap::Args;
+use client::{History, Verdict};
+use common::Part;
+
+use crate::{answers::Answers, config, days, fetch, input::Input, workspace};
+
+#[derive(Args)]
+pub struct SubmitArgs {
+    day: u8,
+    part: Part,
+    /// The answer to submit, otherwise the solution is run against the
+    /// day's input and its answer is submitted.
+    answer: Option<String>,
+    /// Submit even if the guess history says the answer can't be right.
+    #[arg(long)]
+    force: bool,
+}
+
+/// Where the guesses submitted for a day are logged.
+fn history_path(day: u8) -> PathBuf {
+    workspace::day_dir(day).join("guesses.toml")
+}
+
+fn solve(day: u8, part: Part) -> anyhow::Result<String> {
+    let solution =
+        days::get(day).ok_or_else(|| anyhow!("Day {day} isn't solved yet."))?;
+    let input = Input::default();
+    let parsed = solution
+        .parse(&input.read(day)?)
+        .map_err(|e| e.in_input(input.to_string()))?;
+    parsed
+        .solve(part)
+        .with_context(|| format!("Couldn't solve day {day} part {part}"))
+}
+
+pub fn submit(args: SubmitArgs) -> anyhow::Result<()> {
+    let SubmitArgs {
+        day,
+        part,
+        answer,
+        force,
+    } = args;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => solve(day, part)?,
+    };
+    let path = history_path(day);
+    let mut history = History::load(&path)?;
+    if let Err(refusal) = history.check(part, &answer) {
+        if !force {
+            bail!(
+                "Not submitting {answer}. {refusal} Use --force to submit \
+                 anyway."
+            );
+        }
+        println!("{refusal} Submitting anyway.");
+    }
+    println!("Submitting {answer} for day {day} part {part}.");
+    let client = fetch::connect()?;
+    let verdict = client.submit(day, part, &answer)?;
+    println!("{verdict}");
+    history.record(part, &answer, &verdict);
+    history.save(&path)?;
+    if verdict != Verdict::Correct {
+        return Err(anyhow!("The answer wasn't accepted."));
+    }
+    let mut answers = Answers::load()?;
+    answers.record(day, part, &answer);
+    answers.save()?;
+    if part == Part::One {
+        // The puzzle only includes part two once part one is solved.
+        let dir = workspace::day_dir(day);
+        let input = &config::get().input;
+        client::fetch(&dir, day, input, true, || Ok(client))?;
+    }
+    Ok(())
+}
+
+/// Prints every guess submitted for a day and the bounds they imply.
+pub fn guesses(day: u8) -> anyhow::Result<()> {
+    let history = History::load(&history_path(day))?;
+    for part in Part::ALL {
+        let guesses = history.guesses(part);
+        if guesses.is_empty() {
+            println!("Part {part}: no guesses");
+            continue;
+        }
+        println!("Part {part}:");
+        for guess in guesses {
+            println!("  {:>20}  {}", guess.answer, guess.outcome);
+        }
+        match history.bounds(part) {
+            (None, None) => {}
+            (above, below) => {
+                let show =
+                    |n: Option<i128>| n.map_or("?".into(), |n| n.to_string());
+                println!("  between {} and {}", show(above), show(below));
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,263 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use anyhow::{anyhow, bail, Context};
+use clap::Args;
+use common::{puzzle::Puzzle, Part};
+
+use crate::{answers::Answers, input::Input, workspace};
+
+/// How often the watched files are checked for changes.
+const POLL: Duration = Duration::from_millis(300);
+
+#[derive(Args)]
+pub struct WatchArgs {
+    day: u8,
+    /// Only run this part, otherwise both parts are run.
+    #[arg(long)]
+    part: Option<Part>,
+    /// The name of an input in the day's directory or a path to one.
+    #[arg(long, default_value_t)]
+    input: Input,
+    /// Run against this example block from `puzzle.md` instead, see
+    /// `aoc show --examples`.
+    #[arg(long, conflicts_with = "input")]
+    example: Option<usize>,
+    /// Build and run in release mode.
+    #[arg(long)]
+    release: bool,
+}
+
+/// Every file under `dir` with when it was last modified.
+fn modified(dir: &Path, files: &mut BTreeMap<PathBuf, SystemTime>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            modified(&path, files);
+        } else if let Ok(time) = entry.metadata().and_then(|m| m.modified()) {
+            files.insert(path, time);
+        }
+    }
+}
+
+fn snapshot(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    let mut files = BTreeMap::new();
+    for path in paths {
+        if path.is_dir() {
+            modified(path, &mut files);
+        } else if let Ok(time) = fs::metadata(path).and_then(|m| m.modified()) {
+            files.insert(path.clone(), time);
+        }
+    }
+    files
+}
+
+/// The sources of the crate in `dir` and of every crate it depends on by
+/// path, so edits to the shared crates rerun the day too.
+fn sources(dir: &Path, watched: &mut Vec<PathBuf>) {
+    let manifest = dir.join("Cargo.toml");
+    if watched.contains(&manifest) {
+        return;
+    }
+    watched.extend([dir.join("src"), manifest.clone()]);
+    let Ok(text) = fs::read_to_string(&manifest) else {
+        return;
+    };
+    let Ok(manifest) = text.parse::<toml::Table>() else {
+        return;
+    };
+    let Some(dependencies) = manifest.get("dependencies") else {
+        return;
+    };
+    let paths = dependencies
+        .as_table()
+        .into_iter()
+        .flat_map(|dependencies| dependencies.values())
+        .filter_map(|dependency| dependency.get("path")?.as_str());
+    for path in paths {
+        let dependency = dir.join(path);
+        sources(
+            &fs::canonicalize(&dependency).unwrap_or(dependency),
+            watched,
+        );
+    }
+}
+
+/// Builds and runs the day in a child process so edits to the solution are
+/// picked up, returning the answer of every part that was run.
+fn run_once(
+    args: &WatchArgs,
+    example: Option<&str>,
+) -> anyhow::Result<Vec<(Part, String)>> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Command::new(cargo);
+    command.args(["run", "-q", "-p", "aoc"]);
+    if args.release {
+        command.arg("--release");
+    }
+    // The answers are read from the table, whatever `.aoc.toml` prefers.
+    command.args(["--", "run", &args.day.to_string(), "--format", "table"]);
+    if let Some(part) = args.part {
+        command.args(["--part", &part.to_string()]);
+    }
+    let input = match example {
+        Some(_) => "-".to_owned(),
+        None => args.input.to_string(),
+    };
+    command.args(["--input", &input]);
+    let mut child = command
+        .current_dir(workspace::root())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .context("Couldn't start cargo")?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(example.unwrap_or_default().as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        bail!("The run failed.");
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let answers = stdout
+        .lines()
+        .filter_map(|line| {
+            let (part, answer) =
+                line.strip_prefix("Part ")?.split_once(": ")?;
+            Some((part.parse().ok()?, answer.to_owned()))
+        })
+        .collect();
+    Ok(answers)
+}
+
+/// How `answer` compares with the last run and the answer it should be.
+fn describe(
+    answer: &str,
+    previous: Option<&str>,
+    expected: Option<&str>,
+) -> String {
+    let mut notes = Vec::new();
+    match previous {
+        Some(previous) if previous == answer => notes.push("unchanged".into()),
+        Some(previous) => notes.push(format!("was {previous}")),
+        None => {}
+    }
+    match expected {
+        Some(expected) if expected == answer => notes.push("correct".into()),
+        Some(expected) => notes.push(format!("expected {expected}")),
+        None => {}
+    }
+    match notes.is_empty() {
+        true => answer.to_owned(),
+        false => format!("{answer} ({})", notes.join(", ")),
+    }
+}
+
+/// Re-runs a day whenever its source or input changes.
+pub fn watch(args: WatchArgs) -> anyhow::Result<()> {
+    let day = args.day;
+    let dir = workspace::day_dir(day);
+    let puzzle_path = dir.join(client::PUZZLE_FILE);
+    let mut watched = Vec::new();
+    sources(&dir, &mut watched);
+    let answers = Answers::load()?;
+    // The answer each part should give, and the example to run if any.
+    let (expected, example) = match args.example {
+        Some(i) => {
+            watched.push(puzzle_path.clone());
+            let markdown =
+                fs::read_to_string(&puzzle_path).with_context(|| {
+                    format!("Couldn't read {}", puzzle_path.display())
+                })?;
+            let puzzle = Puzzle::parse(&markdown);
+            let block = puzzle.blocks().nth(i).ok_or_else(|| {
+                anyhow!("Day {day} has no example block {i}.")
+            })?;
+            let expected = Part::ALL
+                .map(|part| puzzle.block_answer(i, part).map(str::to_owned));
+            (expected, Some(block.to_owned()))
+        }
+        None => {
+            watched.push(args.input.path(day)?);
+            // Only the real input has stored answers.
+            let real = args.input == Input::default();
+            let expected = Part::ALL.map(|part| {
+                answers.get(day, part).filter(|_| real).map(str::to_owned)
+            });
+            (expected, None)
+        }
+    };
+
+    let mut previous: [Option<String>; 2] = [None, None];
+    let mut seen = BTreeMap::new();
+    loop {
+        let now = snapshot(&watched);
+        if now == seen {
+            thread::sleep(POLL);
+            continue;
+        }
+        seen = now;
+        let source = match args.example {
+            Some(i) => format!("example {i}"),
+            None => args.input.to_string(),
+        };
+        println!("Day {day} on {source}:");
+        match run_once(&args, example.as_deref()) {
+            Ok(answers) => {
+                for (part, answer) in answers {
+                    let i = usize::from(part.number()) - 1;
+                    let previous = previous[i].replace(answer.clone());
+                    let line = describe(
+                        &answer,
+                        previous.as_deref(),
+                        expected[i].as_deref(),
+                    );
+                    println!("  Part {part}: {line}");
+                }
+            }
+            Err(e) => println!("  {e}"),
+        }
+        println!("Watching for changes...");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{describe, sources};
+    use crate::workspace;
+
+    #[test]
+    pub fn descriptions() {
+        assert_eq!(describe("42", None, None), "42");
+        assert_eq!(
+            describe("42", Some("42"), Some("42")),
+            "42 (unchanged, correct)"
+        );
+        assert_eq!(
+            describe("41", Some("40"), Some("42")),
+            "41 (was 40, expected 42)"
+        );
+    }
+
+    #[test]
+    pub fn watches_dependencies() {
+        let root = workspace::root().canonicalize().unwrap();
+        let mut watched = Vec::new();
+        sources(&root.join("days/day4"), &mut watched);
+        for dir in ["days/day4", "crates/common", "crates/grid"] {
+            assert!(watched.contains(&root.join(dir).join("src")), "{dir}");
+        }
+        let common = root.join("crates/common/Cargo.toml");
+        assert_eq!(watched.iter().filter(|&path| *path == common).count(), 1);
+    }
+}
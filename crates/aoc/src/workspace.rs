@@ -0,0 +1,41 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+
+use crate::config;
+
+/// Finds the workspace root by walking up from the current directory to the
+/// first `Cargo.toml` declaring a `[workspace]`, falling back to the root
+/// this binary was built from.
+pub fn root() -> PathBuf {
+    let cwd = env::current_dir().ok();
+    cwd.as_deref()
+        .and_then(|cwd| cwd.ancestors().find(|dir| is_workspace_root(dir)))
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../.."))
+}
+
+fn is_workspace_root(dir: &Path) -> bool {
+    fs::read_to_string(dir.join("Cargo.toml"))
+        .is_ok_and(|manifest| manifest.contains("[workspace]"))
+}
+
+pub fn day_dir(day: u8) -> PathBuf {
+    root().join(config::get().day_path(day))
+}
+
+pub fn session_file() -> PathBuf {
+    root().join(&config::get().session_file)
+}
+
+/// The Advent of Code session cookie saved by `days/init.sh`.
+pub fn session() -> anyhow::Result<String> {
+    let path = session_file();
+    let session = fs::read_to_string(&path).with_context(|| {
+        format!("Couldn't read the session cookie from {}", path.display())
+    })?;
+    Ok(session.trim().to_owned())
+}
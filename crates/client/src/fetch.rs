@@ -0,0 +1,64 @@
+use std::{fs, path::Path};
+
+use anyhow::Context;
+
+use crate::{html, Client};
+
+/// Where a day's puzzle is kept, in the markdown `html::to_markdown` writes.
+pub const PUZZLE_FILE: &str = "puzzle.md";
+/// The name inputs are downloaded as unless the workspace picks another.
+pub const INPUT_FILE: &str = "input";
+
+/// What [`fetch`] had to download rather than finding it cached.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Fetched {
+    pub puzzle: bool,
+    pub input: bool,
+}
+
+impl Client {
+    /// The puzzle description for `day` as markdown.
+    pub fn puzzle(&self, day: u8) -> anyhow::Result<String> {
+        Ok(html::to_markdown(&self.get(&self.day_url(day))?))
+    }
+
+    pub fn input(&self, day: u8) -> anyhow::Result<String> {
+        self.get(&format!("{}/input", self.day_url(day)))
+    }
+}
+
+/// Downloads the puzzle and input of `day` into `dir`, the input to the file
+/// named by `input`, e.g. [`INPUT_FILE`]. Whichever is already there is
+/// skipped, so nothing is fetched twice and a cached day works offline.
+///
+/// `refresh` downloads the puzzle again, which is how part two shows up once
+/// part one is solved. `connect` is only called if something is missing.
+pub fn fetch(
+    dir: &Path,
+    day: u8,
+    input: &str,
+    refresh: bool,
+    connect: impl FnOnce() -> anyhow::Result<Client>,
+) -> anyhow::Result<Fetched> {
+    let puzzle = dir.join(PUZZLE_FILE);
+    let input = dir.join(input);
+    let fetched = Fetched {
+        puzzle: refresh || !puzzle.is_file(),
+        input: !input.is_file(),
+    };
+    if fetched == Fetched::default() {
+        return Ok(fetched);
+    }
+    let client = connect()?;
+    fs::create_dir_all(dir)
+        .with_context(|| format!("Couldn't create {}", dir.display()))?;
+    if fetched.puzzle {
+        fs::write(&puzzle, client.puzzle(day)?)
+            .with_context(|| format!("Couldn't write {}", puzzle.display()))?;
+    }
+    if fetched.input {
+        fs::write(&input, client.input(day)?)
+            .with_context(|| format!("Couldn't write {}", input.display()))?;
+    }
+    Ok(fetched)
+}
@@ -0,0 +1,240 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context;
+use common::Part;
+use serde::{Deserialize, Serialize};
+
+use crate::{Hint, Verdict};
+
+/// What the site said about a guess that it actually checked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Outcome {
+    /// `None` for verdicts where the answer wasn't checked at all.
+    pub fn from_verdict(verdict: &Verdict) -> Option<Self> {
+        match verdict {
+            Verdict::Correct => Some(Self::Correct),
+            Verdict::Wrong { hint: None, .. } => Some(Self::Wrong),
+            Verdict::Wrong {
+                hint: Some(Hint::TooHigh),
+                ..
+            } => Some(Self::TooHigh),
+            Verdict::Wrong {
+                hint: Some(Hint::TooLow),
+                ..
+            } => Some(Self::TooLow),
+            Verdict::TooRecent { .. } | Verdict::AlreadyCompleted => None,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub answer: String,
+    pub outcome: Outcome,
+    /// When the guess was submitted, in seconds since the Unix epoch.
+    pub at: u64,
+}
+
+/// Why a candidate isn't worth submitting.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyGuessed(Outcome),
+    /// The candidate isn't strictly between the highest answer that was too
+    /// low and the lowest answer that was too high.
+    OutOfBounds {
+        above: Option<i128>,
+        below: Option<i128>,
+    },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadyGuessed(Outcome::Correct) => {
+                write!(f, "That answer was already accepted.")
+            }
+            Refusal::AlreadyGuessed(outcome) => {
+                write!(f, "That answer was already rejected as {outcome}.")
+            }
+            Refusal::OutOfBounds { above, below } => {
+                write!(f, "The answer has to be")?;
+                if let Some(above) = above {
+                    write!(f, " above {above}")?;
+                }
+                if above.is_some() && below.is_some() {
+                    write!(f, " and")?;
+                }
+                if let Some(below) = below {
+                    write!(f, " below {below}")?;
+                }
+                write!(f, ".")
+            }
+        }
+    }
+}
+
+/// Every guess submitted for one day, kept so that answers known to be wrong
+/// aren't submitted again.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part1: Vec<Guess>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part2: Vec<Guess>,
+}
+
+impl History {
+    /// Loads a history, which is treated as empty if the file doesn't exist.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text)
+                .with_context(|| format!("Couldn't parse {}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                Ok(Self::default())
+            }
+            Err(e) => Err(e)
+                .with_context(|| format!("Couldn't read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("Couldn't write {}", path.display()))
+    }
+
+    pub fn guesses(&self, part: Part) -> &[Guess] {
+        match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        }
+    }
+
+    fn guesses_mut(&mut self, part: Part) -> &mut Vec<Guess> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+
+    /// The highest answer that was too low and the lowest that was too high.
+    pub fn bounds(&self, part: Part) -> (Option<i128>, Option<i128>) {
+        let numbers = |outcome| {
+            self.guesses(part)
+                .iter()
+                .filter(move |guess| guess.outcome == outcome)
+                .filter_map(|guess| guess.answer.trim().parse::<i128>().ok())
+        };
+        (
+            numbers(Outcome::TooLow).max(),
+            numbers(Outcome::TooHigh).min(),
+        )
+    }
+
+    /// Checks whether `answer` could still be right for `part`.
+    pub fn check(&self, part: Part, answer: &str) -> Result<(), Refusal> {
+        let answer = answer.trim();
+        let earlier = self
+            .guesses(part)
+            .iter()
+            .find(|guess| guess.answer.trim() == answer);
+        if let Some(guess) = earlier {
+            return Err(Refusal::AlreadyGuessed(guess.outcome));
+        }
+        let Ok(number) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        let (above, below) = self.bounds(part);
+        let too_low = above.is_some_and(|above| number <= above);
+        let too_high = below.is_some_and(|below| number >= below);
+        if too_low || too_high {
+            return Err(Refusal::OutOfBounds { above, below });
+        }
+        Ok(())
+    }
+
+    /// Logs the verdict on `answer`, unless the site didn't check it.
+    pub fn record(&mut self, part: Part, answer: &str, verdict: &Verdict) {
+        let Some(outcome) = Outcome::from_verdict(verdict) else {
+            return;
+        };
+        let at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs());
+        self.guesses_mut(part).push(Guess {
+            answer: answer.trim().to_owned(),
+            outcome,
+            at,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part;
+
+    use super::{History, Outcome, Refusal};
+    use crate::{Hint, Verdict};
+
+    fn wrong(hint: Hint) -> Verdict {
+        Verdict::Wrong {
+            hint: Some(hint),
+            wait: None,
+        }
+    }
+
+    #[test]
+    pub fn bounds() {
+        let mut history = History::default();
+        history.record(Part::One, "10", &wrong(Hint::TooLow));
+        history.record(Part::One, "50", &wrong(Hint::TooHigh));
+        history.record(Part::One, "20", &wrong(Hint::TooLow));
+        history.record(Part::One, "30", &Verdict::TooRecent { wait: None });
+        assert_eq!(history.guesses(Part::One).len(), 3);
+        assert_eq!(history.bounds(Part::One), (Some(20), Some(50)));
+        assert_eq!(history.check(Part::One, "30"), Ok(()));
+        assert_eq!(
+            history.check(Part::One, "20"),
+            Err(Refusal::AlreadyGuessed(Outcome::TooLow))
+        );
+        assert_eq!(
+            history.check(Part::One, "15"),
+            Err(Refusal::OutOfBounds {
+                above: Some(20),
+                below: Some(50)
+            })
+        );
+        assert_eq!(history.check(Part::Two, "15"), Ok(()));
+    }
+
+    #[test]
+    pub fn round_trip() {
+        let mut history = History::default();
+        history.record(Part::Two, "abc", &wrong(Hint::TooHigh));
+        let text = toml::to_string(&history).unwrap();
+        assert_eq!(toml::from_str::<History>(&text).unwrap(), history);
+    }
+}
@@ -0,0 +1,223 @@
+//! Just enough HTML handling for the pages the site serves.
+
+pub(crate) fn between<'a>(
+    text: &'a str,
+    start: &str,
+    end: &str,
+) -> Option<&'a str> {
+    let (_, rest) = text.split_once(start)?;
+    Some(rest.split_once(end)?.0)
+}
+
+fn decode(text: &str) -> String {
+    text.replace("&apos;", "'")
+        .replace("&#39;", "'")
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    /// An opening tag by name and the rest of the tag.
+    Open(&'a str, &'a str),
+    Close(&'a str),
+}
+
+/// Splits `html` into tags and the text between them, dropping comments.
+fn tokens(mut html: &str) -> impl Iterator<Item = Token<'_>> {
+    std::iter::from_fn(move || {
+        if html.is_empty() {
+            return None;
+        }
+        let Some(rest) = html.strip_prefix('<') else {
+            let end = html.find('<').unwrap_or(html.len());
+            let (text, rest) = html.split_at(end);
+            html = rest;
+            return Some(Token::Text(text));
+        };
+        if let Some(comment) = rest.strip_prefix("!--") {
+            html = comment.split_once("-->").map_or("", |(_, rest)| rest);
+            return Some(Token::Text(""));
+        }
+        let (tag, rest) = rest.split_once('>').unwrap_or((rest, ""));
+        html = rest;
+        let tag = tag.trim_end_matches('/');
+        if let Some(name) = tag.strip_prefix('/') {
+            return Some(Token::Close(name.trim()));
+        }
+        let (name, attributes) =
+            tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+        Some(Token::Open(name, attributes))
+    })
+}
+
+/// The text of the page's `<article>`, or of the whole page if it has none,
+/// with the tags stripped and whitespace collapsed.
+pub(crate) fn article_text(html: &str) -> String {
+    let article = between(html, "<article>", "</article>").unwrap_or(html);
+    let text: String = tokens(article)
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(decode(text)),
+            _ => None,
+        })
+        .collect();
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Converts the `<main>` of a puzzle page into the markdown `puzzle.md`
+/// files are written in.
+pub fn to_markdown(html: &str) -> String {
+    let main = between(html, "<main>", "</main>").unwrap_or(html);
+    let mut markdown = Markdown::default();
+    for token in tokens(main) {
+        match token {
+            Token::Text(text) => markdown.text(&decode(text)),
+            Token::Open(name, attributes) => markdown.open(name, attributes),
+            Token::Close(name) => markdown.close(name),
+        }
+    }
+    markdown.out.trim_end().to_owned()
+}
+
+#[derive(Default)]
+struct Markdown {
+    out: String,
+    /// The text of the block being written.
+    line: String,
+    pre: bool,
+    code: bool,
+    /// Where the text of each open link starts in `line`, with its target.
+    links: Vec<(usize, String)>,
+    skip: usize,
+}
+
+impl Markdown {
+    fn text(&mut self, text: &str) {
+        if self.skip > 0 {
+            return;
+        }
+        if self.pre || self.code {
+            self.line.push_str(text);
+            return;
+        }
+        for (i, word) in text.split(char::is_whitespace).enumerate() {
+            let spaced = self.line.is_empty() || self.line.ends_with(' ');
+            if i > 0 && !spaced {
+                self.line.push(' ');
+            }
+            self.line.push_str(&word.replace('*', "\\*"));
+        }
+    }
+
+    fn open(&mut self, name: &str, attributes: &str) {
+        match name {
+            "script" | "form" => self.skip += 1,
+            _ if self.skip > 0 => {}
+            "pre" => self.pre = true,
+            "code" if !self.pre => {
+                self.code = true;
+                self.line.push('`');
+            }
+            "em" if !self.pre => self.line.push('*'),
+            "a" if !self.pre => {
+                let href = between(attributes, "href=\"", "\"").unwrap_or("");
+                self.links.push((self.line.len(), decode(href)));
+            }
+            _ => {}
+        }
+    }
+
+    fn close(&mut self, name: &str) {
+        match name {
+            "script" | "form" => self.skip = self.skip.saturating_sub(1),
+            _ if self.skip > 0 => {}
+            "h2" => {
+                let title = self.take();
+                let title = match title.strip_prefix("---") {
+                    Some(rest) => format!("\\---{rest}"),
+                    None => title,
+                };
+                self.out.push_str(&format!("{title}\n----------\n\n"));
+            }
+            "p" => {
+                let text = self.take();
+                self.out.push_str(&format!("{text}\n\n"));
+            }
+            "li" => {
+                let text = self.take();
+                self.out.push_str(&format!("* {text}\n"));
+            }
+            "ul" => self.out.push('\n'),
+            "pre" => {
+                self.pre = false;
+                let block = std::mem::take(&mut self.line);
+                self.out.push_str(&format!("```\n{block}\n```\n\n"));
+            }
+            "code" if !self.pre => {
+                self.code = false;
+                self.line.push('`');
+            }
+            "em" if !self.pre => self.line.push('*'),
+            "a" if !self.pre => {
+                if let Some((start, href)) = self.links.pop() {
+                    self.line.insert(start, '[');
+                    self.line.push_str(&format!("]({href})"));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn take(&mut self) -> String {
+        self.links.clear();
+        std::mem::take(&mut self.line).trim().to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_markdown;
+
+    #[test]
+    pub fn markdown() {
+        let html = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Example ---</h2>
+<p>Pair the <em>smallest</em> numbers, see
+<a href="/2024/about">about</a>:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<ul>
+<li>Distance <code>2</code>.</li>
+<li>Total <code><em>11</em></code> &amp; done.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>11</code>.</p><!-- <p>hidden</p> -->
+<form method="post"><input type="text" name="answer"/></form>
+<p>It provides one gold star: *</p>
+</main>"#;
+        assert_eq!(
+            to_markdown(html),
+            "\\--- Day 1: Example ---
+----------
+
+Pair the *smallest* numbers, see [about](/2024/about):
+
+```
+3   4
+4   3
+
+```
+
+* Distance `2`.
+* Total `*11*` & done.
+
+Your puzzle answer was `11`.
+
+It provides one gold star: \\*"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+mod fetch;
+mod history;
+mod html;
+mod submit;
+
+use anyhow::Context;
+pub use fetch::{fetch, Fetched, INPUT_FILE, PUZZLE_FILE};
+pub use history::{Guess, History, Outcome, Refusal};
+pub use html::to_markdown;
+pub use submit::{Hint, Verdict};
+
+/// Where the puzzles live, overridden in tests to point at a stand-in.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/zphrs/aoc_2024 runner";
+
+/// Talks to the Advent of Code site on behalf of one session.
+pub struct Client {
+    base_url: String,
+    year: u16,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(year: u16, session: impl Into<String>) -> Self {
+        Self {
+            base_url: BASE_URL.into(),
+            year,
+            session: session.into().trim().to_owned(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_owned();
+        self
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{day}", self.base_url, self.year)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn get(&self, url: &str) -> anyhow::Result<String> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &self.cookie())
+            .call()
+            .with_context(|| format!("Couldn't fetch {url}"))?;
+        response
+            .into_string()
+            .with_context(|| format!("Couldn't read the response from {url}"))
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        form: &[(&str, &str)],
+    ) -> anyhow::Result<String> {
+        let response = self
+            .agent
+            .post(url)
+            .set("Cookie", &self.cookie())
+            .send_form(form)
+            .with_context(|| format!("Couldn't post to {url}"))?;
+        response
+            .into_string()
+            .with_context(|| format!("Couldn't read the response from {url}"))
+    }
+}
@@ -0,0 +1,169 @@
+use std::{fmt::Display, time::Duration};
+
+use anyhow::bail;
+use common::Part;
+
+use crate::{
+    html::{article_text, between},
+    Client,
+};
+
+/// The direction the site says a wrong answer is off in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// How the site responded to a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong {
+        hint: Option<Hint>,
+        /// How long until another answer will be accepted.
+        wait: Option<Duration>,
+    },
+    /// An answer was submitted too recently, nothing was checked.
+    TooRecent {
+        wait: Option<Duration>,
+    },
+    /// The part was already solved, nothing was checked.
+    AlreadyCompleted,
+}
+
+impl Verdict {
+    /// Classifies the page returned after posting an answer.
+    pub fn parse(html: &str) -> anyhow::Result<Self> {
+        let text = article_text(html);
+        if text.contains("That's the right answer") {
+            Ok(Self::Correct)
+        } else if text.contains("That's not the right answer") {
+            let hint = if text.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if text.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            let wait = between(&text, "Please wait ", " before trying again")
+                .and_then(parse_wait);
+            Ok(Self::Wrong { hint, wait })
+        } else if text.contains("You gave an answer too recently") {
+            let wait = between(&text, "You have ", " left to wait")
+                .and_then(parse_wait);
+            Ok(Self::TooRecent { wait })
+        } else if text.contains("You don't seem to be solving the right level")
+        {
+            Ok(Self::AlreadyCompleted)
+        } else {
+            bail!("Couldn't make sense of the response: {text}")
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::Wrong { hint, wait } => {
+                write!(f, "That's not the right answer")?;
+                match hint {
+                    Some(Hint::TooHigh) => write!(f, ", it's too high")?,
+                    Some(Hint::TooLow) => write!(f, ", it's too low")?,
+                    None => {}
+                }
+                match wait {
+                    Some(wait) => write!(f, ". Wait {wait:?} to try again."),
+                    None => write!(f, "."),
+                }
+            }
+            Verdict::TooRecent { wait: Some(wait) } => {
+                write!(f, "An answer was given too recently, wait {wait:?}.")
+            }
+            Verdict::TooRecent { wait: None } => {
+                write!(f, "An answer was given too recently.")
+            }
+            Verdict::AlreadyCompleted => {
+                write!(f, "This part was already completed.")
+            }
+        }
+    }
+}
+
+impl Client {
+    /// Posts `answer` for `part` of `day`.
+    pub fn submit(
+        &self,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> anyhow::Result<Verdict> {
+        let url = format!("{}/answer", self.day_url(day));
+        let level = part.number().to_string();
+        let html =
+            self.post_form(&url, &[("level", &level), ("answer", answer)])?;
+        Verdict::parse(&html)
+    }
+}
+
+/// Parses waits like `34s`, `1m 12s` or `one minute`.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    parse_long_wait(&words).or_else(|| parse_short_wait(&words))
+}
+
+fn parse_long_wait(words: &[&str]) -> Option<Duration> {
+    let [amount, unit] = words else {
+        return None;
+    };
+    let amount: u64 = match *amount {
+        "one" => 1,
+        amount => amount.parse().ok()?,
+    };
+    let scale = match unit.trim_end_matches('s') {
+        "second" => 1,
+        "minute" => 60,
+        "hour" => 60 * 60,
+        _ => return None,
+    };
+    Some(Duration::from_secs(amount.checked_mul(scale)?))
+}
+
+fn parse_short_wait(words: &[&str]) -> Option<Duration> {
+    if words.is_empty() {
+        return None;
+    }
+    let mut seconds: u64 = 0;
+    for word in words {
+        let (amount, scale) = if let Some(amount) = word.strip_suffix('s') {
+            (amount, 1)
+        } else if let Some(amount) = word.strip_suffix('m') {
+            (amount, 60)
+        } else {
+            (word.strip_suffix('h')?, 60 * 60)
+        };
+        let amount: u64 = amount.parse().ok()?;
+        seconds = seconds.checked_add(amount.checked_mul(scale)?)?;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::parse_wait;
+
+    #[test]
+    pub fn waits() {
+        assert_eq!(parse_wait("34s"), Some(Duration::from_secs(34)));
+        assert_eq!(parse_wait("1m 12s"), Some(Duration::from_secs(72)));
+        assert_eq!(parse_wait("one minute"), Some(Duration::from_secs(60)));
+        assert_eq!(parse_wait("5 minutes"), Some(Duration::from_secs(300)));
+        assert_eq!(parse_wait("a while"), None);
+        assert_eq!(parse_wait("3é"), None);
+        assert_eq!(parse_wait(" "), None);
+        assert_eq!(parse_wait("9999999999999999h"), None);
+    }
+}
@@ -0,0 +1,85 @@
+mod support;
+
+use std::{env, fs, path::PathBuf};
+
+use anyhow::bail;
+use client::{fetch, Client, Fetched, INPUT_FILE, PUZZLE_FILE};
+use common::{puzzle::Puzzle, Part};
+use support::{page, Server};
+
+const INPUT: &str = "3   4\n4   3\n";
+
+fn cache_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir()
+        .join(format!("aoc-client-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+pub fn downloads_once() {
+    let server = Server::start(vec![
+        ("GET", "/2024/day/1", page("day1.html")),
+        ("GET", "/2024/day/1/input", INPUT.into()),
+    ]);
+    let connect = || Ok(Client::new(2024, "cookie").with_base_url(&server.url));
+    let dir = cache_dir("downloads-once");
+
+    let fetched = fetch(&dir, 1, INPUT_FILE, false, connect).unwrap();
+    assert_eq!(
+        fetched,
+        Fetched {
+            puzzle: true,
+            input: true
+        }
+    );
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].body, "");
+    assert_eq!(requests[1].path, "/2024/day/1/input");
+    assert_eq!(requests[1].cookie.as_deref(), Some("session=cookie"));
+    assert_eq!(fs::read_to_string(dir.join(INPUT_FILE)).unwrap(), INPUT);
+
+    let markdown = fs::read_to_string(dir.join(PUZZLE_FILE)).unwrap();
+    assert!(markdown.starts_with(
+        "\\--- Day 1: Historian Hysteria ---\n----------\n\nThe *Chief \
+         Historian* is always"
+    ));
+    let puzzle = Puzzle::parse(&markdown);
+    assert_eq!(
+        puzzle.example(Part::Two),
+        Some("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n")
+    );
+    assert_eq!(puzzle.example_answer(Part::One), Some("11"));
+    assert_eq!(puzzle.example_answer(Part::Two), Some("31"));
+    assert_eq!(puzzle.answer(Part::Two), Some("20520794"));
+
+    // Everything is cached now, so this works without a connection.
+    let offline = || bail!("offline");
+    assert_eq!(
+        fetch(&dir, 1, INPUT_FILE, false, offline).unwrap(),
+        Fetched::default()
+    );
+
+    let fetched = fetch(&dir, 1, INPUT_FILE, true, connect).unwrap();
+    assert_eq!(
+        fetched,
+        Fetched {
+            puzzle: true,
+            input: false
+        }
+    );
+    assert_eq!(server.requests().len(), 3);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+pub fn missing_day() {
+    let server = Server::start(vec![]);
+    let connect = || Ok(Client::new(2024, "cookie").with_base_url(&server.url));
+    let dir = cache_dir("missing-day");
+    assert!(fetch(&dir, 26, INPUT_FILE, false, connect).is_err());
+    assert!(!dir.join(PUZZLE_FILE).exists());
+    let _ = fs::remove_dir_all(dir);
+}
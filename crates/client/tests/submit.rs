@@ -0,0 +1,67 @@
+mod support;
+
+use std::time::Duration;
+
+use client::{Client, Hint, Verdict};
+use common::Part;
+use support::{page, Server};
+
+fn submit(page_name: &str) -> (Verdict, Server) {
+    let server =
+        Server::start(vec![("POST", "/2024/day/10/answer", page(page_name))]);
+    let client = Client::new(2024, "cookie\n").with_base_url(&server.url);
+    let verdict = client.submit(10, Part::One, "36").unwrap();
+    (verdict, server)
+}
+
+#[test]
+pub fn correct() {
+    let (verdict, server) = submit("correct.html");
+    assert_eq!(verdict, Verdict::Correct);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2024/day/10/answer");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=cookie"));
+    assert_eq!(requests[0].body, "level=1&answer=36");
+}
+
+#[test]
+pub fn wrong() {
+    let (verdict, _) = submit("too-high.html");
+    assert_eq!(
+        verdict,
+        Verdict::Wrong {
+            hint: Some(Hint::TooHigh),
+            wait: Some(Duration::from_secs(60)),
+        }
+    );
+    let (verdict, _) = submit("too-low.html");
+    assert_eq!(
+        verdict,
+        Verdict::Wrong {
+            hint: Some(Hint::TooLow),
+            wait: Some(Duration::from_secs(300)),
+        }
+    );
+}
+
+#[test]
+pub fn too_recent() {
+    let (verdict, _) = submit("too-recent.html");
+    let wait = Some(Duration::from_secs(72));
+    assert_eq!(verdict, Verdict::TooRecent { wait });
+}
+
+#[test]
+pub fn already_completed() {
+    let (verdict, _) = submit("already-completed.html");
+    assert_eq!(verdict, Verdict::AlreadyCompleted);
+}
+
+#[test]
+pub fn unexpected_page() {
+    let server = Server::start(vec![]);
+    let client = Client::new(2024, "cookie").with_base_url(&server.url);
+    assert!(client.submit(10, Part::Two, "36").is_err());
+}
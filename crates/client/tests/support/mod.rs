@@ -0,0 +1,109 @@
+//! A local stand-in for the Advent of Code site that replays recorded pages.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+/// A request the stand-in received.
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// Serves `pages` by method and path until the test ends, answering
+/// anything else with a 404.
+pub struct Server {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl Server {
+    pub fn start(pages: Vec<(&'static str, &'static str, String)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let log = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { continue };
+                let request = read(&stream);
+                // Logged before answering, since the client returns as soon
+                // as it has the response and the test checks the log next.
+                log.lock().unwrap().push(request.clone());
+                respond(stream, &request, &pages);
+            }
+        });
+        Self { url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn read(stream: &TcpStream) -> Request {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    let mut words = line.split_whitespace();
+    let method = words.next().unwrap_or_default().to_owned();
+    let path = words.next().unwrap_or_default().to_owned();
+    let mut length = 0;
+    let mut cookie = None;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).unwrap();
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header.split_once(": ").unwrap_or((header, ""));
+        match name.to_ascii_lowercase().as_str() {
+            "content-length" => length = value.parse().unwrap(),
+            "cookie" => cookie = Some(value.to_owned()),
+            _ => {}
+        }
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).unwrap();
+    Request {
+        method,
+        path,
+        cookie,
+        body: String::from_utf8(body).unwrap(),
+    }
+}
+
+fn respond(
+    mut stream: TcpStream,
+    request: &Request,
+    pages: &[(&'static str, &'static str, String)],
+) {
+    let page = pages
+        .iter()
+        .find(|(m, p, _)| *m == request.method && *p == request.path)
+        .map(|(_, _, page)| page.as_str());
+    let (status, page) = match page {
+        Some(page) => ("200 OK", page),
+        None => ("404 Not Found", "Not found"),
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: text/html\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{page}",
+        page.len()
+    )
+    .unwrap();
+}
+
+/// A page recorded from the site, from `tests/pages`.
+pub fn page(name: &str) -> String {
+    let path = format!("{}/tests/pages/{name}", env!("CARGO_MANIFEST_DIR"));
+    std::fs::read_to_string(path).unwrap()
+}
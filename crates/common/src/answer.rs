@@ -0,0 +1,51 @@
+use std::{error::Error, fmt::Display};
+
+/// Why a part couldn't be answered, e.g. because the answer doesn't fit in
+/// the type it's worked out in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolveError(String);
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self(message.into())
+    }
+
+    pub fn message(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for SolveError {}
+
+/// What a part can answer with: a value shown as it is, or a `Result`
+/// whose error is reported in place of an answer.
+pub trait Answer {
+    fn answer(self) -> Result<String, SolveError>;
+}
+
+macro_rules! shown {
+    ($($ty:ty),*) => {
+        $(
+            impl Answer for $ty {
+                fn answer(self) -> Result<String, SolveError> {
+                    Ok(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+shown!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+shown!(&str, String);
+
+impl<T: Answer, E: Display> Answer for Result<T, E> {
+    fn answer(self) -> Result<String, SolveError> {
+        self.map_err(|e| SolveError::new(e.to_string()))?.answer()
+    }
+}
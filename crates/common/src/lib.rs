@@ -0,0 +1,10 @@
+mod answer;
+mod parse_error;
+mod part;
+pub mod puzzle;
+mod solution;
+
+pub use answer::{Answer, SolveError};
+pub use parse_error::{parse_field, parse_lines, ParseError};
+pub use part::Part;
+pub use solution::{DynSolution, Parsed, Solution};
@@ -0,0 +1,206 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// Why an input couldn't be parsed, and where.
+///
+/// Parsers build it from the text they were given and the slice of it that
+/// was wrong, whoever parses the surrounding input adds the line number, and
+/// the runner adds the day and input name before it's shown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    day: Option<u8>,
+    input: Option<String>,
+    line: Option<usize>,
+    span: Option<Span>,
+}
+
+/// The offending line and the column of the bad character in it, counted in
+/// characters from 0.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Span {
+    text: String,
+    column: usize,
+}
+
+impl ParseError {
+    /// An error about the input as a whole, like a missing section.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            day: None,
+            input: None,
+            line: None,
+            span: None,
+        }
+    }
+
+    /// An error at the character `column` of `text`.
+    pub fn at(text: &str, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            span: Some(Span {
+                text: text.into(),
+                column,
+            }),
+            ..Self::new(message)
+        }
+    }
+
+    /// An error pointing at `bad`, which has to be a slice of `text`.
+    /// Otherwise it points at the start of `text`.
+    pub fn at_slice(text: &str, bad: &str, message: impl Into<String>) -> Self {
+        let offset = (bad.as_ptr() as usize)
+            .checked_sub(text.as_ptr() as usize)
+            .filter(|offset| *offset <= text.len())
+            .unwrap_or(0);
+        let column = text
+            .get(..offset)
+            .map_or(0, |before| before.chars().count());
+        Self::at(text, column, message)
+    }
+
+    /// Moves an error from parsing `inner`, a slice of `text`, onto `text`
+    /// so it points at the same character.
+    pub fn within(mut self, text: &str, inner: &str) -> Self {
+        let Some(span) = self.span.take() else {
+            return Self::at_slice(text, inner, self.message);
+        };
+        let start =
+            Self::at_slice(text, inner, "").span.map_or(0, |s| s.column);
+        self.span = Some(Span {
+            text: text.into(),
+            column: start + span.column,
+        });
+        self
+    }
+
+    /// Sets the line, counted from 1, unless an inner parser already did.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    pub fn in_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn in_input(mut self, input: impl Into<String>) -> Self {
+        self.input = Some(input.into());
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// The column of the bad character, counted from 1.
+    pub fn column(&self) -> Option<usize> {
+        self.span.as_ref().map(|span| span.column + 1)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)?;
+        let mut location = Vec::new();
+        if let Some(day) = self.day {
+            location.push(format!("day {day}"));
+        }
+        let position = match (self.line, self.column()) {
+            (Some(line), Some(column)) => format!("{line}:{column}"),
+            (Some(line), None) => line.to_string(),
+            (None, _) => String::new(),
+        };
+        match (&self.input, position.is_empty()) {
+            (Some(input), true) => location.push(input.clone()),
+            (Some(input), false) => {
+                location.push(format!("{input}:{position}"))
+            }
+            (None, false) => location.push(format!("line {position}")),
+            (None, true) => {}
+        }
+        if !location.is_empty() {
+            write!(f, "\n --> {}", location.join(", "))?;
+        }
+        let Some(span) = &self.span else {
+            return Ok(());
+        };
+        let gutter = self.line.map(|line| line.to_string()).unwrap_or_default();
+        let pad = " ".repeat(gutter.len());
+        write!(f, "\n{pad} |")?;
+        write!(
+            f,
+            "\n{gutter} | {}",
+            span.text.trim_end_matches(['\r', '\n'])
+        )?;
+        write!(f, "\n{pad} | {}^", " ".repeat(span.column))
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `field`, a slice of `text`, pointing at it if it's invalid.
+pub fn parse_field<T>(text: &str, field: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    field.parse().map_err(|e| {
+        ParseError::at_slice(text, field, format!("Invalid {field:?}: {e}"))
+    })
+}
+
+/// Parses every line of `input` as a `T`, numbering errors by their line.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| line.parse().map_err(|e: T::Err| e.on_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseError;
+
+    #[test]
+    pub fn render() {
+        let line = "190: 10 x9";
+        let error = ParseError::at_slice(line, &line[8..], "not a number")
+            .on_line(12)
+            .on_line(1)
+            .in_day(7)
+            .in_input("input");
+        assert_eq!(error.line(), Some(12));
+        assert_eq!(error.column(), Some(9));
+        assert_eq!(
+            error.to_string(),
+            "not a number
+ --> day 7, input:12:9
+   |
+12 | 190: 10 x9
+   |         ^"
+        );
+    }
+
+    #[test]
+    pub fn within() {
+        let line = "75,4x,61";
+        let error =
+            ParseError::at("4x", 1, "not a number").within(line, &line[3..5]);
+        assert_eq!(error.column(), Some(5));
+    }
+
+    #[test]
+    pub fn without_location() {
+        let error = ParseError::new("Guard not found.");
+        assert_eq!(error.to_string(), "Guard not found.");
+    }
+}
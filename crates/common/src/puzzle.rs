@@ -0,0 +1,287 @@
+use std::path::Path;
+
+use crate::{solution::Model, Parsed, Part, Solution};
+
+/// The heading part two starts with.
+pub const PART_TWO: &str = "\\--- Part Two ---";
+/// What the line recording an accepted answer starts with.
+pub const ANSWER: &str = "Your puzzle answer was `";
+
+/// The parts of a `puzzle.md` that can be checked against a solution: the
+/// example blocks, the answers the text gives for them and the answers that
+/// were accepted for the real input.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Puzzle {
+    sections: Vec<Section>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Section {
+    blocks: Vec<String>,
+    emphasized: Vec<String>,
+    answer: Option<String>,
+}
+
+impl Puzzle {
+    pub fn parse(markdown: &str) -> Self {
+        let sections = match markdown.split_once(PART_TWO) {
+            Some((one, two)) => vec![Section::parse(one), Section::parse(two)],
+            None => vec![Section::parse(markdown)],
+        };
+        Self { sections }
+    }
+
+    fn section(&self, part: Part) -> Option<&Section> {
+        self.sections.get(usize::from(part.number()) - 1)
+    }
+
+    /// Every fenced block in the text, in order of appearance.
+    pub fn blocks(&self) -> impl Iterator<Item = &str> {
+        self.sections
+            .iter()
+            .flat_map(|section| &section.blocks)
+            .map(String::as_str)
+    }
+
+    /// The example input for `part`. Part two usually reuses the example
+    /// from part one so it falls back to that when it has none of its own.
+    pub fn example(&self, part: Part) -> Option<&str> {
+        self.section(part)
+            .and_then(|section| section.blocks.first())
+            .or_else(|| self.section(Part::One)?.blocks.first())
+            .map(String::as_str)
+    }
+
+    /// The answer the text gives for the example of `part`, which is the last
+    /// emphasized code span in that part.
+    pub fn example_answer(&self, part: Part) -> Option<&str> {
+        self.section(part)?.emphasized.last().map(String::as_str)
+    }
+
+    /// The answer the text gives for `part` when block `index` of
+    /// [`Puzzle::blocks`] is run as its example, which is when the block is
+    /// in that part, or in part one for a part two without blocks of its
+    /// own.
+    pub fn block_answer(&self, index: usize, part: Part) -> Option<&str> {
+        let mut start = 0;
+        let mut owner = None;
+        for (i, section) in self.sections.iter().enumerate() {
+            let end = start + section.blocks.len();
+            if (start..end).contains(&index) {
+                owner = Some(i);
+            }
+            start = end;
+        }
+        let example_from = match self.section(part)?.blocks.is_empty() {
+            true => 0,
+            false => usize::from(part.number()) - 1,
+        };
+        match owner? == example_from {
+            true => self.example_answer(part),
+            false => None,
+        }
+    }
+
+    /// The accepted answer for the real input, if `part` has been solved.
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        self.section(part)?.answer.as_deref()
+    }
+}
+
+impl Section {
+    fn parse(markdown: &str) -> Self {
+        let mut section = Self::default();
+        let mut block: Option<String> = None;
+        for line in markdown.lines() {
+            if line.trim_end() == "```" {
+                match block.take() {
+                    // The page keeps a blank line before the closing fence
+                    // that real inputs don't have.
+                    Some(done) => {
+                        let done = done.trim_end_matches('\n');
+                        section.blocks.push(format!("{done}\n"));
+                    }
+                    None => block = Some(String::new()),
+                }
+                continue;
+            }
+            if let Some(block) = &mut block {
+                block.push_str(line);
+                block.push('\n');
+                continue;
+            }
+            if let Some(rest) = line.strip_prefix(ANSWER) {
+                section.answer = rest.split_once('`').map(|(a, _)| a.into());
+            }
+            section
+                .emphasized
+                .extend(emphasized(line).map(str::to_owned));
+        }
+        section
+    }
+}
+
+/// The contents of every `` `*...*` `` span on a line.
+fn emphasized(line: &str) -> impl Iterator<Item = &str> {
+    line.split("`*").skip(1).filter_map(|rest| {
+        let (span, _) = rest.split_once("*`")?;
+        (!span.contains('`')).then_some(span)
+    })
+}
+
+fn solve<S: Solution>(input: &str, part: Part) -> String {
+    let input =
+        S::parse(input).unwrap_or_else(|e| panic!("{}", e.in_day(S::DAY)));
+    Model::<S>(input)
+        .solve(part)
+        .unwrap_or_else(|e| panic!("Day {} part {part}: {e}", S::DAY))
+}
+
+fn read(dir: &str, file: &str) -> Option<String> {
+    std::fs::read_to_string(Path::new(dir).join(file)).ok()
+}
+
+/// Checks `part` of `S` against the example in `dir/puzzle.md`. `block`
+/// picks a fenced block by its index in [`Puzzle::blocks`] for puzzles
+/// where the example isn't the first block of the part.
+///
+/// Parts that still answer `todo` and puzzles without an example answer are
+/// skipped.
+pub fn check_example<S: Solution>(dir: &str, part: Part, block: Option<usize>) {
+    let Some(markdown) = read(dir, "puzzle.md") else {
+        eprintln!("Day {}: no puzzle.md, skipping.", S::DAY);
+        return;
+    };
+    let puzzle = Puzzle::parse(&markdown);
+    let Some(expected) = puzzle.example_answer(part) else {
+        eprintln!("Day {}: no example answer for part {part}.", S::DAY);
+        return;
+    };
+    let input = match block {
+        Some(index) => puzzle.blocks().nth(index),
+        None => puzzle.example(part),
+    }
+    .expect("The puzzle should have an example block.");
+    let answer = solve::<S>(input, part);
+    if answer == "todo" {
+        eprintln!("Day {}: part {part} isn't solved yet.", S::DAY);
+        return;
+    }
+    assert_eq!(answer, expected, "Day {} part {part} example", S::DAY);
+}
+
+/// Checks `part` of `S` against `dir/input` and the answer recorded in
+/// `dir/puzzle.md`. Parts without a recorded answer are skipped.
+pub fn check_answer<S: Solution>(dir: &str, part: Part) {
+    let markdown = read(dir, "puzzle.md").unwrap_or_default();
+    let puzzle = Puzzle::parse(&markdown);
+    let Some(expected) = puzzle.answer(part) else {
+        eprintln!("Day {}: no recorded answer for part {part}.", S::DAY);
+        return;
+    };
+    let input = read(dir, "input").expect("The day should have an input.");
+    assert_eq!(
+        solve::<S>(&input, part),
+        expected,
+        "Day {} part {part}",
+        S::DAY
+    );
+}
+
+/// Generates `#[test]`s checking both parts of a day against the examples
+/// and recorded answers in its `puzzle.md`. The tests against the real input
+/// are ignored by default since some days take a while in debug builds, run
+/// them with `cargo test -- --ignored`.
+///
+/// The example for a part can be overridden by block index:
+///
+/// ```ignore
+/// common::puzzle_tests!(crate::Day4, part1 = 1, part2 = 1);
+/// ```
+#[macro_export]
+macro_rules! puzzle_tests {
+    (
+        $day:ty
+        $(, part1 = $part1:expr)?
+        $(, part2 = $part2:expr)?
+        $(,)?
+    ) => {
+        #[test]
+        pub fn part1_example() {
+            $crate::puzzle::check_example::<$day>(
+                env!("CARGO_MANIFEST_DIR"),
+                $crate::Part::One,
+                None $(.or(Some($part1)))?,
+            );
+        }
+
+        #[test]
+        pub fn part2_example() {
+            $crate::puzzle::check_example::<$day>(
+                env!("CARGO_MANIFEST_DIR"),
+                $crate::Part::Two,
+                None $(.or(Some($part2)))?,
+            );
+        }
+
+        #[test]
+        #[ignore = "runs the full input"]
+        pub fn part1_answer() {
+            $crate::puzzle::check_answer::<$day>(
+                env!("CARGO_MANIFEST_DIR"),
+                $crate::Part::One,
+            );
+        }
+
+        #[test]
+        #[ignore = "runs the full input"]
+        pub fn part2_answer() {
+            $crate::puzzle::check_answer::<$day>(
+                env!("CARGO_MANIFEST_DIR"),
+                $crate::Part::Two,
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Puzzle;
+    use crate::Part;
+
+    const MARKDOWN: &str = "\
+\\--- Day 1: Test ---
+
+```
+1 2
+3 4
+
+```
+
+So the answer is `*10*`.
+
+Your puzzle answer was `1234`.
+
+\\--- Part Two ---
+
+Now it's `*not*` `*24*`.
+";
+
+    #[test]
+    pub fn parse() {
+        let puzzle = Puzzle::parse(MARKDOWN);
+        assert_eq!(puzzle.blocks().collect::<Vec<_>>(), ["1 2\n3 4\n"]);
+        assert_eq!(puzzle.example(Part::Two), Some("1 2\n3 4\n"));
+        assert_eq!(puzzle.example_answer(Part::One), Some("10"));
+        assert_eq!(puzzle.example_answer(Part::Two), Some("24"));
+        assert_eq!(puzzle.answer(Part::One), Some("1234"));
+        assert_eq!(puzzle.answer(Part::Two), None);
+        assert_eq!(puzzle.block_answer(0, Part::Two), Some("24"));
+        assert_eq!(puzzle.block_answer(1, Part::One), None);
+        let own = MARKDOWN.replace("Now it's", "```\n5\n```\nNow it's");
+        let puzzle = Puzzle::parse(&own);
+        assert_eq!(puzzle.block_answer(0, Part::One), Some("10"));
+        assert_eq!(puzzle.block_answer(0, Part::Two), None);
+        assert_eq!(puzzle.block_answer(1, Part::Two), Some("24"));
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{Answer, ParseError, Part, SolveError};
+
+/// A day's puzzle: the input is parsed into a typed model once and both
+/// parts are answered from that model.
+pub trait Solution {
+    const DAY: u8;
+    type Input;
+    type Part1: Answer;
+    type Part2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+
+    /// A human readable breakdown of how the answers come about, for days
+    /// where that helps track down a wrong one.
+    fn report(_input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+/// Object safe view of a [`Solution`] so that every day can be stored in one
+/// registry.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, ParseError>;
+}
+
+/// A parsed model that can answer either part as a string.
+pub trait Parsed {
+    fn solve(&self, part: Part) -> Result<String, SolveError>;
+    fn report(&self) -> Option<String>;
+}
+
+pub(crate) struct Model<S: Solution>(pub(crate) S::Input);
+
+impl<S: Solution> Parsed for Model<S> {
+    fn solve(&self, part: Part) -> Result<String, SolveError> {
+        match part {
+            Part::One => S::part1(&self.0).answer(),
+            Part::Two => S::part2(&self.0).answer(),
+        }
+    }
+
+    fn report(&self) -> Option<String> {
+        S::report(&self.0)
+    }
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync + 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Parsed>, ParseError> {
+        let input = S::parse(input).map_err(|e| e.in_day(S::DAY))?;
+        Ok(Box::new(Model::<S>(input)))
+    }
+}
@@ -0,0 +1,172 @@
+use crate::Position;
+
+/// An axis aligned rectangle of positions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    min: Position,
+    /// One past the largest position on each axis.
+    end: Position,
+}
+
+impl Bounds {
+    /// Bounds containing both `min` and `max`.
+    pub fn inclusive(min: Position, max: Position) -> Self {
+        assert!(min.x() <= max.x());
+        assert!(min.y() <= max.y());
+        Self {
+            min,
+            end: max + Position::new(1, 1),
+        }
+    }
+
+    /// Bounds containing `min` but stopping just short of `end`.
+    pub fn exclusive(min: Position, end: Position) -> Self {
+        assert!(min.x() <= end.x());
+        assert!(min.y() <= end.y());
+        Self { min, end }
+    }
+
+    /// The `width` by `height` bounds with the origin as the top left.
+    pub fn from_size(width: usize, height: usize) -> Self {
+        Self::exclusive(
+            Position::ZERO,
+            Position::new(width as isize, height as isize),
+        )
+    }
+
+    /// The smallest bounds containing every one of `positions`, `None` if
+    /// there aren't any.
+    pub fn enclosing(
+        positions: impl IntoIterator<Item = Position>,
+    ) -> Option<Self> {
+        let mut positions = positions.into_iter();
+        let first = positions.next()?;
+        let (min, max) = positions.fold((first, first), |(min, max), p| {
+            (
+                Position::new(min.x().min(p.x()), min.y().min(p.y())),
+                Position::new(max.x().max(p.x()), max.y().max(p.y())),
+            )
+        });
+        Some(Self::inclusive(min, max))
+    }
+
+    pub fn min(&self) -> Position {
+        self.min
+    }
+
+    /// The largest position inside the bounds. Meaningless when the bounds
+    /// are empty.
+    pub fn max(&self) -> Position {
+        self.end - Position::new(1, 1)
+    }
+
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    pub fn width(&self) -> usize {
+        (self.end.x() - self.min.x()) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.end.y() - self.min.y()) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width() == 0 || self.height() == 0
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        self.min.x() <= pos.x()
+            && self.min.y() <= pos.y()
+            && pos.x() < self.end.x()
+            && pos.y() < self.end.y()
+    }
+
+    /// Whether `other` lies entirely inside these bounds.
+    pub fn contains_bounds(&self, other: Bounds) -> bool {
+        other.is_empty()
+            || (self.contains(other.min) && self.contains(other.max()))
+    }
+
+    /// The overlap of both bounds, `None` if they don't overlap.
+    pub fn intersection(&self, other: Bounds) -> Option<Bounds> {
+        let min = Position::new(
+            self.min.x().max(other.min.x()),
+            self.min.y().max(other.min.y()),
+        );
+        let end = Position::new(
+            self.end.x().min(other.end.x()),
+            self.end.y().min(other.end.y()),
+        );
+        (min.x() < end.x() && min.y() < end.y())
+            .then(|| Self::exclusive(min, end))
+    }
+
+    /// Grows every side outwards by `by`, or shrinks them when `by` is
+    /// negative.
+    pub fn expanded(&self, by: isize) -> Self {
+        let by = Position::new(by, by);
+        Self::exclusive(self.min - by, self.end + by)
+    }
+
+    /// Every position inside the bounds in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = Position> {
+        let Self { min, end } = *self;
+        (min.y()..end.y())
+            .flat_map(move |y| (min.x()..end.x()).map(move |x| (x, y).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Bounds;
+    use crate::Position;
+
+    #[test]
+    pub fn inclusive_and_exclusive() {
+        let inclusive =
+            Bounds::inclusive(Position::new(0, 0), Position::new(2, 1));
+        let exclusive =
+            Bounds::exclusive(Position::new(0, 0), Position::new(3, 2));
+        assert_eq!(inclusive, exclusive);
+        assert!(inclusive.contains(Position::new(2, 1)));
+        assert!(!exclusive.contains(Position::new(3, 1)));
+        assert_eq!(inclusive.iter().count(), 6);
+        assert_eq!(inclusive.iter().last(), Some(Position::new(2, 1)));
+    }
+
+    #[test]
+    pub fn intersection() {
+        let a = Bounds::from_size(4, 4);
+        let b = Bounds::inclusive(Position::new(2, -1), Position::new(5, 2));
+        assert_eq!(
+            a.intersection(b),
+            Some(Bounds::inclusive(Position::new(2, 0), Position::new(3, 2)))
+        );
+        assert!(Bounds::from_size(0, 3).is_empty());
+        assert_eq!(
+            a.expanded(-1),
+            Bounds::inclusive(Position::new(1, 1), Position::new(2, 2))
+        );
+        assert_eq!(
+            a.intersection(Bounds::exclusive(
+                Position::new(4, 0),
+                Position::new(5, 5)
+            )),
+            None
+        );
+    }
+
+    #[test]
+    pub fn enclosing() {
+        let bounds = Bounds::enclosing([
+            Position::new(3, 1),
+            Position::new(-1, 4),
+            Position::new(0, 0),
+        ])
+        .unwrap();
+        assert_eq!(bounds.min(), Position::new(-1, 0));
+        assert_eq!(bounds.max(), Position::new(3, 4));
+    }
+}
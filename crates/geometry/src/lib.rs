@@ -0,0 +1,5 @@
+mod bounds;
+mod vector;
+
+pub use bounds::Bounds;
+pub use vector::{Direction, Position, Vector};
@@ -0,0 +1,199 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A 2D integer vector where `x` grows to the right and `y` grows downwards,
+/// the same way the puzzle inputs are read.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector {
+    x: isize,
+    y: isize,
+}
+
+/// A point on a map.
+pub type Position = Vector;
+
+/// A step between two points on a map.
+pub type Direction = Vector;
+
+impl Vector {
+    pub const ZERO: Vector = Vector::new(0, 0);
+
+    pub const UP: Vector = Vector::new(0, -1);
+    pub const DOWN: Vector = Vector::new(0, 1);
+    pub const LEFT: Vector = Vector::new(-1, 0);
+    pub const RIGHT: Vector = Vector::new(1, 0);
+
+    pub const UP_LEFT: Vector = Vector::new(-1, -1);
+    pub const UP_RIGHT: Vector = Vector::new(1, -1);
+    pub const DOWN_LEFT: Vector = Vector::new(-1, 1);
+    pub const DOWN_RIGHT: Vector = Vector::new(1, 1);
+
+    /// The orthogonal directions, clockwise from up.
+    pub const NEIGHBOURS4: [Vector; 4] =
+        [Self::UP, Self::RIGHT, Self::DOWN, Self::LEFT];
+
+    /// The orthogonal and diagonal directions, clockwise from up.
+    pub const NEIGHBOURS8: [Vector; 8] = [
+        Self::UP,
+        Self::UP_RIGHT,
+        Self::RIGHT,
+        Self::DOWN_RIGHT,
+        Self::DOWN,
+        Self::DOWN_LEFT,
+        Self::LEFT,
+        Self::UP_LEFT,
+    ];
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn x(&self) -> isize {
+        self.x
+    }
+
+    pub fn y(&self) -> isize {
+        self.y
+    }
+
+    /// Rotates a quarter turn clockwise, so up becomes right.
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotates a quarter turn counter-clockwise, so up becomes left.
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    pub fn rotate_180(self) -> Self {
+        -self
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The smallest integer step pointing the same way, so `(4, -6)`
+    /// becomes `(2, -3)`. Walking by the reduced step visits every integer
+    /// point on the line.
+    pub fn reduced(self) -> Self {
+        let divisor = gcd(self.x.unsigned_abs(), self.y.unsigned_abs());
+        if divisor == 0 {
+            return self;
+        }
+        Self::new(self.x / divisor as isize, self.y / divisor as isize)
+    }
+
+    /// Clamps each component to `-1`, `0` or `1`.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl Display for Vector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl AddAssign for Vector {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Vector {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Vector {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<A, B> From<(A, B)> for Vector
+where
+    A: Into<isize>,
+    B: Into<isize>,
+{
+    fn from(value: (A, B)) -> Self {
+        Vector::new(value.0.into(), value.1.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Vector;
+
+    #[test]
+    pub fn rotations() {
+        for direction in Vector::NEIGHBOURS8 {
+            assert_eq!(direction.rotate_right().rotate_left(), direction);
+            assert_eq!(
+                direction.rotate_right().rotate_right(),
+                direction.rotate_180()
+            );
+        }
+        assert_eq!(Vector::UP.rotate_right(), Vector::RIGHT);
+        assert_eq!(Vector::UP.rotate_left(), Vector::LEFT);
+        assert_eq!(Vector::UP_RIGHT.rotate_right(), Vector::DOWN_RIGHT);
+    }
+
+    #[test]
+    pub fn distances() {
+        let a = Vector::new(1, -2);
+        let b = Vector::new(-3, 4);
+        assert_eq!(a.manhattan_distance(b), 10);
+        assert_eq!(a.chebyshev_distance(b), 6);
+    }
+
+    #[test]
+    pub fn reduced() {
+        assert_eq!(Vector::new(4, -6).reduced(), Vector::new(2, -3));
+        assert_eq!(Vector::new(0, 5).reduced(), Vector::DOWN);
+        assert_eq!(Vector::ZERO.reduced(), Vector::ZERO);
+    }
+}
@@ -0,0 +1,299 @@
+mod view;
+
+use std::fmt::Display;
+
+use common::ParseError;
+use geometry::{Bounds, Direction, Position};
+pub use view::View;
+
+/// A dense, row-major 2D map.
+///
+/// Positions are signed so that stepping off an edge is just an out of
+/// bounds lookup instead of an underflow.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width == 0 || cells.len().is_multiple_of(width),
+            "Cells should fill every row."
+        );
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line, mapping every character to a cell.
+    pub fn from_lines<I, S>(lines: I, mut f: impl FnMut(char) -> T) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self::from_lines_with_position(lines, |_, c| f(c))
+    }
+
+    /// Like [`Grid::from_lines`] but also hands the mapping the position of
+    /// the character.
+    pub fn from_lines_with_position<I, S>(
+        lines: I,
+        mut f: impl FnMut(Position, char) -> T,
+    ) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in lines.into_iter().enumerate() {
+            let before = cells.len();
+            cells.extend(
+                line.as_ref()
+                    .chars()
+                    .enumerate()
+                    .map(|(x, c)| f(Position::new(x as isize, y as isize), c)),
+            );
+            let row_width = cells.len() - before;
+            let width = *width.get_or_insert(row_width);
+            assert_eq!(width, row_width, "Row {y} has a different width.");
+            height += 1;
+        }
+        Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line of `input`. `f` maps every character to a
+    /// cell or explains why it can't, and rows of different widths are
+    /// reported too.
+    pub fn parse(
+        input: &str,
+        mut f: impl FnMut(Position, char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            let before = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                let pos = Position::new(x as isize, y as isize);
+                let cell = f(pos, c)
+                    .map_err(|e| ParseError::at(line, x, e).on_line(y + 1))?;
+                cells.push(cell);
+            }
+            let row_width = cells.len() - before;
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                let message = format!(
+                    "Row is {row_width} wide but the first is {width}."
+                );
+                let column = row_width.min(width);
+                return Err(
+                    ParseError::at(line, column, message).on_line(y + 1)
+                );
+            }
+            height += 1;
+        }
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        Bounds::from_size(self.width, self.height)
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        self.bounds().contains(pos)
+    }
+
+    fn index_of(&self, pos: Position) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.x() as usize + pos.y() as usize * self.width)
+    }
+
+    fn position_of(&self, index: usize) -> Position {
+        Position::new(
+            (index % self.width) as isize,
+            (index / self.width) as isize,
+        )
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every cell in reading order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    /// Every cell in reading order alongside its position.
+    pub fn indexed(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.position_of(i), cell))
+    }
+
+    /// The position of every cell matching `predicate`, in reading order.
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Position> + 'a {
+        self.indexed()
+            .filter_map(move |(pos, cell)| predicate(cell).then_some(pos))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height)
+            .then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks_exact` panics on zero, an empty grid has no rows anyway.
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    /// The up to four orthogonal neighbours of `pos` that are on the grid.
+    pub fn neighbours4(
+        &self,
+        pos: Position,
+    ) -> impl Iterator<Item = (Position, &T)> {
+        self.neighbours(pos, &Direction::NEIGHBOURS4)
+    }
+
+    /// The up to eight orthogonal and diagonal neighbours of `pos` that are
+    /// on the grid.
+    pub fn neighbours8(
+        &self,
+        pos: Position,
+    ) -> impl Iterator<Item = (Position, &T)> {
+        self.neighbours(pos, &Direction::NEIGHBOURS8)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        pos: Position,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = (Position, &'a T)> {
+        directions.iter().filter_map(move |direction| {
+            let next = pos + *direction;
+            self.get(next).map(|cell| (next, cell))
+        })
+    }
+
+    /// A borrowed window onto the cells within `bounds`. Returns `None` if
+    /// the bounds aren't fully on the grid.
+    pub fn view(&self, bounds: Bounds) -> Option<View<'_, T>> {
+        View::new(self, bounds)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y != 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geometry::Position;
+
+    use super::Grid;
+
+    const MAP: &str = "..#\n#..\n.#.";
+
+    #[test]
+    pub fn round_trip() {
+        let grid = Grid::from_lines(MAP.lines(), |c| c);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid.to_string(), MAP);
+    }
+
+    #[test]
+    pub fn parse() {
+        let grid = Grid::parse(MAP, |_, c| Ok(c)).unwrap();
+        assert_eq!(grid.to_string(), MAP);
+        let error = Grid::parse("..\n...", |_, c| Ok(c)).unwrap_err();
+        assert_eq!((error.line(), error.column()), (Some(2), Some(3)));
+        let error = Grid::parse(MAP, |_, c| match c {
+            '.' => Ok(()),
+            c => Err(format!("Unexpected {c:?}")),
+        });
+        assert_eq!(error.unwrap_err().column(), Some(3));
+    }
+
+    #[test]
+    pub fn bounds() {
+        let grid = Grid::from_lines(MAP.lines(), |c| c == '#');
+        assert_eq!(grid.get(Position::new(2, 0)), Some(&true));
+        assert_eq!(grid.get(Position::new(-1, 0)), None);
+        assert_eq!(grid.get(Position::new(3, 0)), None);
+        assert_eq!(grid.neighbours4(Position::ZERO).count(), 2);
+        let blocked = grid.neighbours8(Position::new(1, 1));
+        assert_eq!(blocked.filter(|(_, c)| **c).count(), 3);
+        let column: Vec<bool> = grid.column(0).unwrap().copied().collect();
+        assert_eq!(column, [false, true, false]);
+    }
+}
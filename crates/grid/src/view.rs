@@ -0,0 +1,111 @@
+use std::fmt::Display;
+
+use geometry::{Bounds, Position};
+
+use crate::Grid;
+
+/// A rectangular window onto a [`Grid`], positioned relative to its own top
+/// left corner.
+#[derive(Clone, Copy)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    bounds: Bounds,
+}
+
+impl<'a, T> View<'a, T> {
+    pub(crate) fn new(grid: &'a Grid<T>, bounds: Bounds) -> Option<Self> {
+        grid.bounds()
+            .contains_bounds(bounds)
+            .then_some(Self { grid, bounds })
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds.height()
+    }
+
+    /// Where the view's top left corner is on the underlying grid.
+    pub fn origin(&self) -> Position {
+        self.bounds.min()
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&'a T> {
+        let pos = pos + self.origin();
+        if !self.bounds.contains(pos) {
+            return None;
+        }
+        self.grid.get(pos)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        let min = self.origin();
+        // An empty view can sit anywhere, even off the grid.
+        let height = match self.bounds.is_empty() {
+            true => 0,
+            false => self.height(),
+        };
+        (0..height).map(move |y| {
+            let row = self
+                .grid
+                .row(min.y() as usize + y)
+                .expect("View was checked to be on the grid.");
+            &row[min.x() as usize..min.x() as usize + self.width()]
+        })
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = self.rows().flatten().cloned().collect();
+        Grid::from_vec(self.width(), cells)
+    }
+}
+
+impl<T: Display> Display for View<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y != 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geometry::{Bounds, Position};
+
+    use crate::Grid;
+
+    #[test]
+    pub fn view() {
+        let grid = Grid::from_lines(["abc", "def", "ghi"], |c| c);
+        let bounds =
+            Bounds::inclusive(Position::new(1, 1), Position::new(2, 2));
+        let view = grid.view(bounds).unwrap();
+        assert_eq!(view.to_string(), "ef\nhi");
+        assert_eq!(view.get(Position::new(0, 1)), Some(&'h'));
+        assert_eq!(view.get(Position::new(2, 0)), None);
+        assert!(grid.view(bounds.expanded(1)).is_none());
+        assert_eq!(view.to_grid().to_string(), "ef\nhi");
+    }
+
+    #[test]
+    pub fn empty_view() {
+        let grid = Grid::from_lines(["abc", "def"], |c| c);
+        let off_grid =
+            Bounds::exclusive(Position::new(-5, 7), Position::new(-5, 9));
+        let view = grid.view(off_grid).unwrap();
+        assert_eq!(view.rows().count(), 0);
+        assert_eq!(view.to_string(), "");
+        assert_eq!(view.get(Position::ZERO), None);
+    }
+}
@@ -0,0 +1,198 @@
+mod parse;
+#[cfg(test)]
+mod reference;
+mod report;
+mod sort;
+mod value;
+
+use std::str::FromStr;
+
+use common::{ParseError, Solution};
+pub use report::Report;
+pub use value::{Overflow, Value};
+
+/// Both lists, each sorted, which is all either part needs.
+pub struct Lists<T> {
+    left: Vec<T>,
+    right: Vec<T>,
+}
+
+impl<T: Value> Lists<T> {
+    pub fn new(mut left: Vec<T>, mut right: Vec<T>) -> Self {
+        sort::sort(&mut left);
+        sort::sort(&mut right);
+        Self { left, right }
+    }
+
+    /// The sum of the distances between the smallest values of each list,
+    /// then the second smallest, and so on.
+    pub fn distance(&self) -> Result<T::Distance, Overflow> {
+        self.left.iter().zip(&self.right).try_fold(
+            T::Distance::ZERO,
+            |sum, (&l, &r)| {
+                sum.checked_add(l.distance(r))
+                    .ok_or(Overflow::of::<T::Distance>("total distance"))
+            },
+        )
+    }
+
+    /// The sum of every left value times how often it's in the right list.
+    /// Both lists are sorted, so equal values are next to each other and the
+    /// counts come from walking them together rather than from a table.
+    pub fn similarity(&self) -> Result<T, Overflow> {
+        let overflow = Overflow::of::<T>("similarity score");
+        let mut right = runs(&self.right).peekable();
+        let mut score = T::ZERO;
+        for (value, times) in runs(&self.left) {
+            while right.next_if(|&(r, _)| r < value).is_some() {}
+            let Some((_, count)) = right.next_if(|&(r, _)| r == value) else {
+                continue;
+            };
+            let times = times
+                .checked_mul(count)
+                .and_then(|times| T::try_from(times).ok())
+                .ok_or(overflow)?;
+            score = value
+                .checked_mul(times)
+                .and_then(|contribution| score.checked_add(contribution))
+                .ok_or(overflow)?;
+        }
+        Ok(score)
+    }
+}
+
+impl<T: Value> FromStr for Lists<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (left, right) = parse::columns(s)?;
+        Ok(Self::new(left, right))
+    }
+}
+
+/// Each distinct value of a sorted list with how often it's in it.
+fn runs<T: Value>(sorted: &[T]) -> impl Iterator<Item = (T, usize)> + '_ {
+    sorted
+        .chunk_by(|a, b| a == b)
+        .map(|run| (run[0], run.len()))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Lists<i64>;
+    type Part1 = Result<u64, Overflow>;
+    type Part2 = Result<i64, Overflow>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        input.distance()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        input.similarity()
+    }
+
+    fn report(input: &Self::Input) -> Option<String> {
+        Some(input.report().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use crate::{reference, Day1, Lists, Overflow};
+
+    common::puzzle_tests!(crate::Day1);
+
+    /// Lines of two pseudo-random numbers below `max`, from a fixed seed.
+    fn generate(lines: usize, max: u32, mut seed: u64) -> String {
+        let mut next = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (seed >> 33) as u32 % max
+        };
+        (0..lines)
+            .map(|_| format!("{}   {}\n", next(), next()))
+            .collect()
+    }
+
+    #[test]
+    pub fn matches_reference() {
+        for (lines, max, seed) in
+            [(0, 1, 0), (1, 10, 1), (500, 20, 2), (2000, 100_000, 3)]
+        {
+            let input = generate(lines, max, seed);
+            let (left, right) = reference::parse(&input).unwrap();
+            let lists: Lists<u32> = input.parse().unwrap();
+            assert_eq!(
+                lists.distance(),
+                Ok(reference::p1(left.clone(), right.clone()))
+            );
+            assert_eq!(lists.similarity(), Ok(reference::p2(left, right)));
+        }
+    }
+
+    #[test]
+    pub fn parse_errors() {
+        let error = Day1::parse("3   4\n5 x\n").err().unwrap();
+        assert_eq!((error.line(), error.column()), (Some(2), Some(3)));
+        let error = Day1::parse("34\n").err().unwrap();
+        assert_eq!(error.message(), "Expected two numbers split by spaces.");
+        let error = Day1::parse("1 99999999999999999999\n").err().unwrap();
+        assert_eq!(error.message(), "The number doesn't fit.");
+        let error = "-1 2".parse::<Lists<u64>>().err().unwrap();
+        assert_eq!(error.message(), "Expected a number.");
+    }
+
+    #[test]
+    pub fn widths() {
+        let input = "-3   4\n4   -3\n4   4\n";
+        let lists: Lists<i32> = input.parse().unwrap();
+        assert_eq!(lists.distance(), Ok(0));
+        assert_eq!(lists.similarity(), Ok(16 - 3));
+        let big = format!("{0}   {0}\n", u64::MAX);
+        let lists: Lists<u128> = big.parse().unwrap();
+        assert_eq!(lists.similarity(), Ok(u128::from(u64::MAX)));
+    }
+
+    #[test]
+    pub fn report() {
+        let lists: Lists<u32> = "1   3\n7   1\n2   3\n".parse().unwrap();
+        let report = lists.report().to_string();
+        let table = "Left  Right  Distance  Count  Contribution\n   \
+                     1      1         0      1             1\n   \
+                     2      3         1      0             0\n   \
+                     7      3         4      0             0\n";
+        assert!(report.starts_with(table), "{report}");
+        assert!(report.contains("Total distance: 5\n"));
+        assert!(report.contains("Similarity score: 1\n"));
+        assert!(report.contains("missing from the right list: 2, 7\n"));
+        assert!(report.ends_with(
+            "Distances:\n    0         1  ####################\n  \
+             1-9         2  ########################################\n"
+        ));
+    }
+
+    #[test]
+    pub fn overflow() {
+        let lists: Lists<u32> =
+            "4000000000   0\n0   4000000000\n".parse().unwrap();
+        assert_eq!(lists.distance(), Ok(0));
+        let lists: Lists<u32> =
+            "0   4000000000\n1   4000000000\n".parse().unwrap();
+        assert_eq!(
+            lists.distance(),
+            Err(Overflow::of::<u32>("total distance"))
+        );
+        let lists: Lists<u32> = "3000000000   3000000000\n".parse().unwrap();
+        assert_eq!(lists.similarity(), Ok(3_000_000_000));
+        let lists: Lists<u32> =
+            "3000000000   3000000000\n3000000000   0\n".parse().unwrap();
+        assert!(lists.similarity().is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use common::ParseError;
+
+use crate::value::Value;
+
+/// Reads the two columns straight from the input's bytes in one pass,
+/// without allocating anything per line. Columns can be split by any run of
+/// spaces or tabs.
+pub fn columns<T: Value>(input: &str) -> Result<(Vec<T>, Vec<T>), ParseError> {
+    let bytes = input.as_bytes();
+    // Every line but maybe the last ends in a newline, so this is the line
+    // count give or take one.
+    let lines = bytes.iter().filter(|&&b| b == b'\n').count() + 1;
+    let mut left = Vec::with_capacity(lines);
+    let mut right = Vec::with_capacity(lines);
+    let mut at = 0;
+    while at < bytes.len() {
+        if matches!(bytes[at], b'\n' | b'\r') {
+            at += 1;
+            continue;
+        }
+        let l = number(bytes, &mut at).map_err(|m| error(input, at, m))?;
+        let gap = at;
+        while at < bytes.len() && matches!(bytes[at], b' ' | b'\t') {
+            at += 1;
+        }
+        if at == gap {
+            let message = "Expected two numbers split by spaces.";
+            return Err(error(input, at, message));
+        }
+        let r = number(bytes, &mut at).map_err(|m| error(input, at, m))?;
+        if at < bytes.len() && !matches!(bytes[at], b'\n' | b'\r') {
+            return Err(error(input, at, "Expected the end of the line."));
+        }
+        left.push(l);
+        right.push(r);
+    }
+    Ok((left, right))
+}
+
+/// An error at the byte `at` of `input`, which is only worked out into a
+/// line and column once something went wrong.
+fn error(input: &str, at: usize, message: &str) -> ParseError {
+    let start = input[..at].rfind('\n').map_or(0, |i| i + 1);
+    let end = input[at..].find('\n').map_or(input.len(), |i| at + i);
+    let line = input[..at].matches('\n').count() + 1;
+    ParseError::at_slice(&input[start..end], &input[at..], message)
+        .on_line(line)
+}
+
+/// Reads the digits at `at`, after a `-` if `T` is signed, as a number,
+/// leaving `at` after them, or where the number went wrong.
+fn number<T: Value>(bytes: &[u8], at: &mut usize) -> Result<T, &'static str> {
+    let negative = T::SIGNED && bytes.get(*at) == Some(&b'-');
+    if negative {
+        *at += 1;
+    }
+    let start = *at;
+    let mut value = T::ZERO;
+    while let Some(digit) = bytes.get(*at).filter(|b| b.is_ascii_digit()) {
+        value = value
+            .push_digit(digit - b'0', negative)
+            .ok_or("The number doesn't fit.")?;
+        *at += 1;
+    }
+    if *at == start {
+        return Err("Expected a number.");
+    }
+    Ok(value)
+}
@@ -0,0 +1,56 @@
+//! The original solution, which sorts with `sort` and counts with a
+//! `HashMap`. It's only kept to check the faster one against.
+
+use std::collections::HashMap;
+
+use common::{parse_field, ParseError};
+
+pub fn parse(s: &str) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
+    let mut left: Vec<u32> = Vec::new();
+    let mut right: Vec<u32> = Vec::new();
+    for (i, line) in s.lines().enumerate() {
+        let (l, r) = line.split_once("   ").ok_or_else(|| {
+            ParseError::at(
+                line,
+                0,
+                "Expected two numbers split by three spaces.",
+            )
+            .on_line(i + 1)
+        })?;
+        left.push(parse_field(line, l).map_err(|e| e.on_line(i + 1))?);
+        right.push(parse_field(line, r).map_err(|e| e.on_line(i + 1))?);
+    }
+    Ok((left, right))
+}
+
+pub fn p1(mut left: Vec<u32>, mut right: Vec<u32>) -> u32 {
+    left.sort();
+    right.sort();
+
+    let sum: u32 = left
+        .into_iter()
+        .zip(right)
+        .fold(0, |sum, (curr_l, curr_r)| sum + curr_r.abs_diff(curr_l));
+    sum
+}
+
+pub fn p2(mut left: Vec<u32>, right: Vec<u32>) -> u32 {
+    let r_buckets: HashMap<u32, u32> =
+        right
+            .into_iter()
+            .fold(Default::default(), |mut buckets, elem| {
+                let v = buckets.get_mut(&elem);
+                if let Some(v) = v {
+                    *v += 1;
+                } else {
+                    buckets.insert(elem, 1);
+                }
+                buckets
+            });
+
+    left.sort();
+
+    left.into_iter().fold(0, |sum, elem| {
+        sum + elem * r_buckets.get(&elem).unwrap_or(&0)
+    })
+}
@@ -0,0 +1,174 @@
+use std::fmt::Display;
+
+use crate::{runs, Lists, Value};
+
+/// How many of the largest distances and missing values are listed.
+const SHOWN: usize = 10;
+/// How wide the longest bar of the histogram is.
+const BAR: usize = 40;
+
+/// One row of the pairing table: the `i`th smallest value of each list.
+struct Pair<T: Value> {
+    left: T,
+    right: T,
+    distance: T::Distance,
+    /// How often `left` is in the right list.
+    count: usize,
+}
+
+impl<T: Value> Pair<T> {
+    fn contribution(&self) -> Option<T> {
+        let count = T::try_from(self.count).ok()?;
+        self.left.checked_mul(count)
+    }
+}
+
+fn shown(value: Option<impl Display>) -> String {
+    value.map_or("overflow".into(), |value| value.to_string())
+}
+
+/// Sorts distances into buckets of 0, 1-9, 10-99 and so on.
+fn bucket(distance: u128) -> usize {
+    match distance {
+        0 => 0,
+        d => d.ilog10() as usize + 1,
+    }
+}
+
+fn bucket_label(bucket: usize) -> String {
+    match bucket {
+        0 => "0".into(),
+        b => {
+            let low = 10u128.pow(b as u32 - 1);
+            format!("{low}-{}", low.saturating_mul(10) - 1)
+        }
+    }
+}
+
+impl<T: Value> Lists<T> {
+    fn pairs(&self) -> Vec<Pair<T>> {
+        let mut right = runs(&self.right).peekable();
+        let counts = runs(&self.left).flat_map(|(value, times)| {
+            while right.next_if(|&(r, _)| r < value).is_some() {}
+            let count = right.peek().filter(|&&(r, _)| r == value);
+            std::iter::repeat_n(count.map_or(0, |&(_, count)| count), times)
+        });
+        self.left
+            .iter()
+            .zip(&self.right)
+            .zip(counts)
+            .map(|((&left, &right), count)| Pair {
+                left,
+                right,
+                distance: left.distance(right),
+                count,
+            })
+            .collect()
+    }
+
+    /// The sorted pairs with what each adds to both parts, then the largest
+    /// distances, the left values the right list doesn't have and how the
+    /// distances are spread.
+    pub fn report(&self) -> Report<'_, T> {
+        Report(self)
+    }
+}
+
+pub struct Report<'a, T>(&'a Lists<T>);
+
+impl<T: Value> Display for Report<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lists = self.0;
+        let pairs = lists.pairs();
+        let rows: Vec<[String; 5]> = pairs
+            .iter()
+            .map(|pair| {
+                [
+                    pair.left.to_string(),
+                    pair.right.to_string(),
+                    pair.distance.to_string(),
+                    pair.count.to_string(),
+                    shown(pair.contribution()),
+                ]
+            })
+            .collect();
+        let header = ["Left", "Right", "Distance", "Count", "Contribution"];
+        let mut widths = header.map(str::len);
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.len());
+            }
+        }
+        for row in std::iter::once(header.map(String::from)).chain(rows) {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{cell:>width$}"))
+                .collect();
+            writeln!(f, "{}", cells.join("  "))?;
+        }
+        writeln!(f)?;
+        writeln!(f, "Pairs: {}", pairs.len())?;
+        writeln!(f, "Total distance: {}", shown(lists.distance().ok()))?;
+        writeln!(f, "Similarity score: {}", shown(lists.similarity().ok()))?;
+        if lists.left.len() != lists.right.len() {
+            writeln!(
+                f,
+                "The lists have {} and {} values, the rest aren't paired.",
+                lists.left.len(),
+                lists.right.len()
+            )?;
+        }
+
+        let mut largest: Vec<&Pair<T>> = pairs.iter().collect();
+        largest.sort_by_key(|pair| std::cmp::Reverse(pair.distance));
+        writeln!(f, "\nLargest distances:")?;
+        for pair in largest.iter().take(SHOWN) {
+            let Pair {
+                left,
+                right,
+                distance,
+                ..
+            } = pair;
+            writeln!(f, "  {distance} between {left} and {right}")?;
+        }
+
+        let mut missing: Vec<T> = pairs
+            .iter()
+            .filter(|pair| pair.count == 0)
+            .map(|pair| pair.left)
+            .collect();
+        missing.dedup();
+        let listed: Vec<String> =
+            missing.iter().take(SHOWN).map(T::to_string).collect();
+        let listed = match listed.is_empty() {
+            true => vec!["none".to_owned()],
+            false => listed,
+        };
+        write!(f, "\nLeft values missing from the right list: ")?;
+        write!(f, "{}", listed.join(", "))?;
+        if missing.len() > SHOWN {
+            write!(f, " and {} more", missing.len() - SHOWN)?;
+        }
+        writeln!(f)?;
+
+        let mut histogram = Vec::new();
+        for pair in &pairs {
+            let bucket = bucket(pair.distance.key());
+            if histogram.len() <= bucket {
+                histogram.resize(bucket + 1, 0);
+            }
+            histogram[bucket] += 1;
+        }
+        writeln!(f, "\nDistances:")?;
+        let most = histogram.iter().copied().max().unwrap_or(1);
+        let labels: Vec<String> =
+            (0..histogram.len()).map(bucket_label).collect();
+        let width = labels.iter().map(String::len).max().unwrap_or(0);
+        for (label, &count) in labels.iter().zip(&histogram) {
+            let bar = "#".repeat((count * BAR).div_ceil(most));
+            writeln!(f, "  {label:>width$}  {count:>8}  {bar}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use crate::value::Value;
+
+/// Sorts `values` in linear time: by counting each value when they're
+/// packed into a range no wider than the list is long, as in inputs of
+/// millions of five digit numbers, and with a radix sort otherwise.
+pub fn sort<T: Value>(values: &mut Vec<T>) {
+    let (Some(&min), Some(&max)) = (values.iter().min(), values.iter().max())
+    else {
+        return;
+    };
+    if max.key() - min.key() < values.len() as u128 {
+        counting_sort(values, min.key(), max.key());
+    } else {
+        radix_sort(values);
+    }
+}
+
+/// Sorts `values`, whose keys are all between `min` and `max`, by counting
+/// how often each value is in it and writing them out again in order.
+fn counting_sort<T: Value>(values: &mut [T], min: u128, max: u128) {
+    let mut counts = vec![0usize; (max - min) as usize + 1];
+    for &value in values.iter() {
+        counts[(value.key() - min) as usize] += 1;
+    }
+    let mut slots = values.iter_mut();
+    for (key, &count) in (min..=max).zip(&counts) {
+        let value = T::from_key(key);
+        for slot in slots.by_ref().take(count) {
+            *slot = value;
+        }
+    }
+}
+
+/// Sorts `values` with a least significant digit radix sort, one byte per
+/// pass. Passes where every value has the same byte are skipped.
+fn radix_sort<T: Value>(values: &mut Vec<T>) {
+    let mut scratch = vec![T::ZERO; values.len()];
+    for shift in (0..T::BYTES * 8).step_by(8) {
+        let byte = |value: T| (value.key() >> shift) as u8 as usize;
+        let mut counts = [0usize; 256];
+        for &value in values.iter() {
+            counts[byte(value)] += 1;
+        }
+        if counts.contains(&values.len()) {
+            continue;
+        }
+        let mut start = 0;
+        for count in &mut counts {
+            (*count, start) = (start, start + *count);
+        }
+        for &value in values.iter() {
+            let slot = &mut counts[byte(value)];
+            scratch[*slot] = value;
+            *slot += 1;
+        }
+        std::mem::swap(values, &mut scratch);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sort;
+
+    #[test]
+    pub fn sorts() {
+        let wide = vec![70000, 3, 0, u32::MAX, 256, 3, 65536, 255];
+        let packed = vec![12, 10, 15, 12, 11, 10, 14, 13];
+        for mut values in [wide, packed, vec![]] {
+            let mut expected = values.clone();
+            expected.sort();
+            sort(&mut values);
+            assert_eq!(values, expected);
+        }
+        let mut signed = vec![-5, i64::MAX, 3, i64::MIN, 0, -5, 1 << 40];
+        let mut expected = signed.clone();
+        expected.sort();
+        sort(&mut signed);
+        assert_eq!(signed, expected);
+    }
+}
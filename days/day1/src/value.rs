@@ -0,0 +1,114 @@
+use std::{any::type_name, error::Error, fmt::Display};
+
+/// An integer type the lists can be read into.
+pub trait Value: Copy + Ord + Display + TryFrom<usize> {
+    /// What the distance between two values is measured in, which can't be
+    /// negative.
+    type Distance: Value;
+    const ZERO: Self;
+    const SIGNED: bool;
+    const BYTES: u32;
+
+    /// `self` with `digit` appended, away from zero on the side `negative`
+    /// says, if it still fits.
+    fn push_digit(self, digit: u8, negative: bool) -> Option<Self>;
+    fn distance(self, other: Self) -> Self::Distance;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    /// Maps values onto unsigned keys in the same order, so sorting can
+    /// look at their bytes.
+    fn key(self) -> u128;
+    fn from_key(key: u128) -> Self;
+}
+
+macro_rules! value {
+    ($($ty:ty => $distance:ty),* $(,)?) => {
+        $(
+            impl Value for $ty {
+                type Distance = $distance;
+                const ZERO: Self = 0;
+                const SIGNED: bool = <$ty>::MIN != 0;
+                const BYTES: u32 = <$ty>::BITS / 8;
+
+                fn push_digit(self, digit: u8, negative: bool) -> Option<Self> {
+                    let digit = digit as Self;
+                    let shifted = self.checked_mul(10)?;
+                    match negative {
+                        true => shifted.checked_sub(digit),
+                        false => shifted.checked_add(digit),
+                    }
+                }
+
+                fn distance(self, other: Self) -> Self::Distance {
+                    self.abs_diff(other)
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    self.checked_add(other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    self.checked_mul(other)
+                }
+
+                fn key(self) -> u128 {
+                    // Flipping the sign bit puts negative values first.
+                    (self ^ <$ty>::MIN) as $distance as u128
+                }
+
+                fn from_key(key: u128) -> Self {
+                    (key as $distance as $ty) ^ <$ty>::MIN
+                }
+            }
+        )*
+    };
+}
+
+value!(
+    u32 => u32,
+    u64 => u64,
+    u128 => u128,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+);
+
+/// A total that got too big for the type it's added up in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Overflow {
+    total: &'static str,
+    ty: &'static str,
+}
+
+impl Overflow {
+    pub fn of<T>(total: &'static str) -> Self {
+        Self {
+            total,
+            ty: type_name::<T>(),
+        }
+    }
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The {} doesn't fit in {}.", self.total, self.ty)
+    }
+}
+
+impl Error for Overflow {}
+
+#[cfg(test)]
+mod tests {
+    use super::Value;
+
+    #[test]
+    pub fn keys_keep_order() {
+        let values = [i32::MIN, -7, -1, 0, 1, 7, i32::MAX];
+        let keys: Vec<u128> = values.iter().map(|v| v.key()).collect();
+        assert!(keys.is_sorted());
+        for (&value, &key) in values.iter().zip(&keys) {
+            assert_eq!(i32::from_key(key), value);
+        }
+        assert_eq!(u64::MAX.key(), u128::from(u64::MAX));
+    }
+}
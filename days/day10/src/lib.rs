@@ -0,0 +1,52 @@
+use std::fmt::Display;
+
+use common::{ParseError, Solution};
+use grid::Grid;
+
+/// A height from `0` to `9`, or `None` for the impassable `.` tiles that the
+/// examples use.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Height(Option<u8>);
+
+impl Display for Height {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(height) => write!(f, "{height}"),
+            None => write!(f, "."),
+        }
+    }
+}
+
+pub type TopographicMap = Grid<Height>;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = TopographicMap;
+    type Part1 = &'static str;
+    type Part2 = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, |_, c| match c {
+            '.' => Ok(Height(None)),
+            c => c
+                .to_digit(10)
+                .map(|d| Height(Some(d as u8)))
+                .ok_or_else(|| format!("{c:?} isn't a height.")),
+        })
+    }
+
+    fn part1(_input: &Self::Input) -> Self::Part1 {
+        "todo"
+    }
+
+    fn part2(_input: &Self::Input) -> Self::Part2 {
+        "todo"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::puzzle_tests!(crate::Day10);
+}
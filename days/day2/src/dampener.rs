@@ -0,0 +1,113 @@
+use crate::{Direction, Report, SafetyPolicy};
+
+/// Whether `policy` lets `from` be followed by `to` when levels have to go
+/// `direction`, which is never `Either`. Plateaus the other rules would
+/// refuse are let through at the cost of one of the policy's plateaus.
+fn step(
+    policy: &SafetyPolicy,
+    direction: Direction,
+    from: i16,
+    to: i16,
+) -> Option<usize> {
+    let diff = to - from;
+    let right_way = match direction {
+        Direction::Increasing => diff > 0,
+        Direction::Decreasing => diff < 0,
+        Direction::Either | Direction::Any => true,
+    };
+    let size = diff.abs();
+    if right_way && (policy.min_step..=policy.max_step).contains(&size) {
+        Some(0)
+    } else if diff == 0 && policy.plateaus > 0 {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+/// The levels to keep for the fewest removals when levels have to go
+/// `direction`, if there are at most `limit` of them.
+///
+/// `fewest[i][p]` is how few levels before `i` can be removed for the
+/// levels up to `i` to be safe with `i` kept and `p` plateaus used. Only
+/// the `limit + 1` levels before `i` can come before it, any further back
+/// would remove too many.
+fn keep(
+    levels: &[i16],
+    policy: &SafetyPolicy,
+    direction: Direction,
+    limit: usize,
+) -> Option<(usize, Vec<usize>)> {
+    let n = levels.len();
+    let plateaus = policy.plateaus.min(n);
+    let mut fewest = vec![vec![None; plateaus + 1]; n];
+    // The kept level before `i`, for each number of plateaus.
+    let mut before = vec![vec![None; plateaus + 1]; n];
+    for i in 0..n {
+        if i <= limit {
+            fewest[i][0] = Some(i);
+        }
+        for j in i.saturating_sub(limit + 1)..i {
+            let Some(cost) = step(policy, direction, levels[j], levels[i])
+            else {
+                continue;
+            };
+            for p in 0..=plateaus - cost {
+                let Some(removed) = fewest[j][p] else {
+                    continue;
+                };
+                let removed = removed + i - j - 1;
+                let slot = &mut fewest[i][p + cost];
+                if removed <= limit && slot.is_none_or(|best| removed < best) {
+                    *slot = Some(removed);
+                    before[i][p + cost] = Some((j, p));
+                }
+            }
+        }
+    }
+    let (removed, mut last) = (0..n)
+        .flat_map(|i| (0..=plateaus).map(move |p| (i, p)))
+        .filter_map(|(i, p)| Some((fewest[i][p]? + n - 1 - i, (i, p))))
+        .filter(|&(removed, _)| removed <= limit)
+        .min()?;
+    let mut kept = vec![last.0];
+    while let Some(previous) = before[last.0][last.1] {
+        kept.push(previous.0);
+        last = previous;
+    }
+    kept.reverse();
+    Some((removed, kept))
+}
+
+impl Report {
+    /// The fewest levels that have to be removed for the report to be safe
+    /// under `policy`, by index, as long as there are at most `limit` of
+    /// them.
+    pub fn dampen(
+        &self,
+        policy: &SafetyPolicy,
+        limit: usize,
+    ) -> Option<Vec<usize>> {
+        let levels: Vec<i16> = self.0.iter().map(|&l| l.into()).collect();
+        if levels.len() <= 1 {
+            return Some(Vec::new());
+        }
+        let directions = match policy.direction {
+            Direction::Either => {
+                vec![Direction::Increasing, Direction::Decreasing]
+            }
+            direction => vec![direction],
+        };
+        // No more than every level can be removed.
+        let limit = limit.min(levels.len());
+        let (_, kept) = directions
+            .into_iter()
+            .filter_map(|direction| keep(&levels, policy, direction, limit))
+            .min_by_key(|(removed, _)| *removed)?;
+        let mut removed = vec![true; levels.len()];
+        for i in kept {
+            removed[i] = false;
+        }
+        Some((0..levels.len()).filter(|&i| removed[i]).collect())
+    }
+}
@@ -0,0 +1,357 @@
+mod dampener;
+mod policy;
+
+use std::{ops::Sub, str::FromStr};
+
+use common::{parse_field, parse_lines, ParseError, Solution};
+pub use policy::{Direction, SafetyPolicy};
+use thiserror::Error;
+
+pub struct Report(Vec<u8>);
+#[derive(Clone, Copy)]
+pub struct Pair<T>(Element<T>, Element<T>)
+where
+    T: Sub<T, Output = T>;
+
+impl<T: std::ops::Sub<Output = T>> From<(Element<T>, Element<T>)> for Pair<T> {
+    fn from(value: (Element<T>, Element<T>)) -> Self {
+        Pair(value.0, value.1)
+    }
+}
+
+impl Pair<i16> {
+    pub fn direction(&self) -> i16 {
+        let Self(c, n) = *self;
+        (n - c).signum()
+    }
+    pub fn is_valid(
+        &self,
+        prev_elem: Option<Element<i16>>,
+        correct_direction: i16,
+        policy: &SafetyPolicy,
+    ) -> Result<(), Error<i16>> {
+        let Self(c, n) = *self;
+        let diff = n - c;
+        let sign = diff.signum();
+        if diff.abs() < policy.min_step {
+            return Err(Error::diff_too_small(*self));
+        }
+        let wrong_way = match policy.direction {
+            Direction::Increasing => sign != 1,
+            Direction::Decreasing => sign != -1,
+            Direction::Either => sign == 0 || sign != correct_direction,
+            Direction::Any => false,
+        };
+        if wrong_way {
+            return Err(match prev_elem {
+                Some(prev_elem) => Error::pyramid(prev_elem, self.0, self.1),
+                None => Error::wrong_direction(*self),
+            });
+        }
+        if diff.abs() > policy.max_step {
+            return Err(Error::diff_too_big(*self));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Element<T>(T, usize);
+
+impl<T> From<(usize, T)> for Element<T> {
+    fn from(value: (usize, T)) -> Self {
+        Element(value.1, value.0)
+    }
+}
+
+impl<T> Sub for Element<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = T;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.0 - rhs.0
+    }
+}
+
+#[derive(Error)]
+pub enum Error<T> {
+    #[error("Difference between previous {0} and next {1} is too small.")]
+    DiffTooSmall(Element<T>, Element<T>),
+    #[error("Difference between previous {0} and next {1} is too big.")]
+    DiffTooBig(Element<T>, Element<T>),
+    #[error("Direction Pyramid: {0}, {1}, and {2}.")]
+    Pyramid(Element<T>, Element<T>, Element<T>),
+    #[error("Previous {0} and next {1} go the wrong way.")]
+    WrongDirection(Element<T>, Element<T>),
+}
+
+impl<T> Error<T>
+where
+    T: Sub<Output = T>,
+{
+    pub fn diff_too_small(pair: Pair<T>) -> Self {
+        Self::DiffTooSmall(pair.0, pair.1)
+    }
+
+    pub fn diff_too_big(pair: Pair<T>) -> Self {
+        Self::DiffTooBig(pair.0, pair.1)
+    }
+
+    pub fn wrong_direction(pair: Pair<T>) -> Self {
+        Self::WrongDirection(pair.0, pair.1)
+    }
+
+    pub fn pyramid(
+        left: Element<T>,
+        middle: Element<T>,
+        right: Element<T>,
+    ) -> Self {
+        Self::Pyramid(left, middle, right)
+    }
+}
+
+impl Report {
+    pub fn is_valid(&self, policy: &SafetyPolicy) -> Result<(), Error<i16>> {
+        let curr = self.0.iter().enumerate();
+        let mut next = curr.clone();
+        next.next();
+        let iter = curr.zip(next).map::<Pair<i16>, _>(|(c, n)| {
+            ((c.0, *c.1 as i16).into(), (n.0, *n.1 as i16).into()).into()
+        });
+        let mut plateaus = policy.plateaus;
+        // Set by the first pair that goes up or down.
+        let mut direction = None;
+        let mut prev = None;
+        for pair in iter {
+            let direction = match pair.direction() {
+                0 => direction.unwrap_or(0),
+                sign => *direction.get_or_insert(sign),
+            };
+            // A plateau is only charged when the rules would refuse it.
+            match pair.is_valid(prev, direction, policy) {
+                Err(_) if pair.direction() == 0 && plateaus > 0 => {
+                    plateaus -= 1
+                }
+                result => result?,
+            }
+            prev = Some(pair.0);
+        }
+        Ok(())
+    }
+
+    pub fn to_fixed(&self, elem: Element<i16>) -> Self {
+        Self(
+            self.0
+                .iter()
+                .enumerate()
+                .filter_map(|(i, e)| if i == elem.1 { None } else { Some(*e) })
+                .collect(),
+        )
+    }
+
+    pub fn _naive_is_valid_with_fix(&self, policy: &SafetyPolicy) -> bool {
+        let Err(_) = self.is_valid(policy) else {
+            return true;
+        };
+        for e in self.0.iter().enumerate() {
+            let elem: Element<i16> = (e.0, 0).into();
+            if self.to_fixed(elem).is_valid(policy).is_ok() {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl FromStr for Report {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner: Result<Vec<u8>, _> =
+            s.split(' ').map(|level| parse_field(s, level)).collect();
+        Ok(Self(inner?))
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<Report>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        let policy = SafetyPolicy::default();
+        input.iter().filter(|r| r.is_valid(&policy).is_ok()).count()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        let policy = SafetyPolicy::default();
+        input
+            .iter()
+            .filter(|r| r.dampen(&policy, 1).is_some())
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Direction, Report, SafetyPolicy};
+    #[test]
+    pub fn simple() {
+        let report: Report = "1 2 3 4 5".parse().unwrap();
+        assert!(report.is_valid(&SafetyPolicy::default()).is_ok())
+    }
+    #[test]
+    pub fn big_gap() {
+        let report: Report = "1 5".parse().unwrap();
+        assert!(report.is_valid(&SafetyPolicy::default()).is_err())
+    }
+    #[test]
+    pub fn policies() {
+        let safe = |report: &str, policy: SafetyPolicy| {
+            report.parse::<Report>().unwrap().is_valid(&policy).is_ok()
+        };
+        let puzzle = SafetyPolicy::default();
+        let wide = SafetyPolicy {
+            max_step: 5,
+            ..puzzle
+        };
+        assert!(!safe("1 2 7 8 9", puzzle));
+        assert!(safe("1 2 7 8 9", wide));
+        let decreasing = SafetyPolicy {
+            direction: Direction::Decreasing,
+            ..puzzle
+        };
+        assert!(safe("7 6 4 2 1", decreasing));
+        assert!(!safe("1 3 6 7 9", decreasing));
+        let any = SafetyPolicy {
+            direction: Direction::Any,
+            ..puzzle
+        };
+        assert!(safe("1 3 2 4 5", any));
+        let plateau = SafetyPolicy {
+            plateaus: 1,
+            ..puzzle
+        };
+        assert!(safe("8 6 4 4 1", plateau));
+        assert!(!safe("8 8 6 4 4 1", plateau));
+        assert!(safe("4 4 5 6", plateau));
+        let flat = SafetyPolicy {
+            min_step: 0,
+            direction: Direction::Any,
+            ..plateau
+        };
+        let increasing = SafetyPolicy {
+            direction: Direction::Increasing,
+            ..flat
+        };
+        assert!(safe("4 4 4 5", flat));
+        assert!(safe("4 4 5", increasing));
+        assert!(!safe("4 4 4 5", increasing));
+    }
+
+    /// The fewest removals making `report` safe, found by trying every
+    /// subset of levels.
+    fn fewest_removals(report: &Report, policy: &SafetyPolicy) -> usize {
+        let n = report.0.len();
+        (0u32..1 << n)
+            .filter(|removed| {
+                let kept = (0..n)
+                    .filter(|i| removed & (1 << i) == 0)
+                    .map(|i| report.0[i])
+                    .collect();
+                Report(kept).is_valid(policy).is_ok()
+            })
+            .map(u32::count_ones)
+            .min()
+            .unwrap() as usize
+    }
+
+    #[test]
+    pub fn dampener() {
+        let puzzle = SafetyPolicy::default();
+        let report: Report = "1 3 2 4 5".parse().unwrap();
+        assert_eq!(report.dampen(&puzzle, 0), None);
+        assert_eq!(report.dampen(&puzzle, 1).map(|r| r.len()), Some(1));
+        let report: Report = "9 1 2 3 9 4 0 5".parse().unwrap();
+        assert_eq!(report.dampen(&puzzle, 2), None);
+        assert_eq!(report.dampen(&puzzle, 3), Some(vec![0, 4, 6]));
+
+        // From `adversarial.txt`.
+        for line in ["4 5 3 2 1", "4 5 6 3 2 1"] {
+            let report: Report = line.parse().unwrap();
+            assert_eq!(
+                report.dampen(&puzzle, 1).is_some(),
+                report._naive_is_valid_with_fix(&puzzle),
+                "{line}"
+            );
+        }
+
+        let plateau = SafetyPolicy {
+            plateaus: 1,
+            min_step: 2,
+            max_step: 4,
+            ..puzzle
+        };
+        let policies = [
+            puzzle,
+            plateau,
+            SafetyPolicy {
+                direction: Direction::Any,
+                ..puzzle
+            },
+            SafetyPolicy {
+                direction: Direction::Increasing,
+                ..plateau
+            },
+            SafetyPolicy {
+                direction: Direction::Decreasing,
+                ..puzzle
+            },
+            SafetyPolicy {
+                min_step: 0,
+                direction: Direction::Any,
+                ..plateau
+            },
+        ];
+        let mut seed = 7u64;
+        for _ in 0..300 {
+            let levels = (0..8)
+                .map(|_| {
+                    seed =
+                        seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+                    (seed >> 59) as u8
+                })
+                .collect();
+            let report = Report(levels);
+            for policy in policies {
+                let fewest = fewest_removals(&report, &policy);
+                let removed = report.dampen(&policy, 8).unwrap();
+                assert_eq!(
+                    report.dampen(&policy, usize::MAX),
+                    Some(removed.clone())
+                );
+                assert_eq!(removed.len(), fewest, "{:?}", report.0);
+                let kept = (0..8)
+                    .filter(|i| !removed.contains(i))
+                    .map(|i| report.0[i])
+                    .collect();
+                assert!(Report(kept).is_valid(&policy).is_ok());
+                assert_eq!(
+                    report.dampen(&policy, fewest.saturating_sub(1)).is_some(),
+                    fewest == 0
+                );
+            }
+        }
+    }
+
+    common::puzzle_tests!(crate::Day2);
+}
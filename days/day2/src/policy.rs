@@ -0,0 +1,37 @@
+/// Which way the levels of a safe report have to go.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// Every level is higher than the one before.
+    Increasing,
+    /// Every level is lower than the one before.
+    Decreasing,
+    /// Either increasing or decreasing, as long as it sticks to one.
+    Either,
+    /// Levels can go up and down, only the steps are checked.
+    Any,
+}
+
+/// The rules a report has to follow to be safe. The default is the
+/// puzzle's: steps of 1 to 3, all in the same direction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    /// The smallest difference allowed between neighbouring levels.
+    pub min_step: i16,
+    /// The biggest difference allowed between neighbouring levels.
+    pub max_step: i16,
+    pub direction: Direction,
+    /// How many times neighbouring levels the step and direction rules
+    /// refuse for being equal are let through anyway.
+    pub plateaus: usize,
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        Self {
+            min_step: 1,
+            max_step: 3,
+            direction: Direction::Either,
+            plateaus: 0,
+        }
+    }
+}
@@ -1,11 +1,8 @@
-use std::fmt::Display;
-use std::fs::File;
-use std::io::{self, BufRead, BufReader, Lines};
-use std::iter::Map;
 use std::str::FromStr;
 use std::sync::OnceLock;
 
 use anyhow::anyhow;
+use common::{ParseError, Solution};
 use regex::Regex;
 
 struct SumInstruction(u16, u16);
@@ -52,10 +49,10 @@ impl TryFrom<regex::Captures<'_>> for Instruction {
 }
 
 #[derive(Default)]
-struct InstructionSet(Vec<Instruction>);
+pub struct InstructionSet(Vec<Instruction>);
 
 impl FromStr for InstructionSet {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let regexp = INSTR_REGEX.get_or_init(|| {
@@ -63,6 +60,8 @@ impl FromStr for InstructionSet {
                 .expect("Should be a valid regex")
         });
         let iter = regexp.captures_iter(s);
+        // Anything that doesn't make a valid instruction is just part of the
+        // corrupted memory, so it's skipped rather than reported.
         let inner: Vec<Instruction> =
             iter.filter_map(|c| c.try_into().ok()).collect();
         Ok(Self(inner))
@@ -96,45 +95,32 @@ impl InstructionSet {
     }
 }
 
-pub fn part1_solution(
-    lines: Map<
-        Lines<BufReader<File>>,
-        impl FnMut(Result<String, io::Error>) -> String,
-    >,
-) -> impl Display {
-    let mut sum = 0u64;
-    for line in lines {
-        let set: InstructionSet = line.parse().unwrap();
-        sum += set.evaluate(true);
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = InstructionSet;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut set = InstructionSet::default();
+        for line in input.lines() {
+            set.append(line.parse()?);
+        }
+        Ok(set)
     }
-    sum
-}
 
-pub fn part2_solution(
-    lines: Map<
-        Lines<BufReader<File>>,
-        impl FnMut(Result<String, io::Error>) -> String,
-    >,
-) -> impl Display {
-    let set: InstructionSet =
-        lines.fold(Default::default(), |mut prev, line| {
-            prev.append(line.parse().unwrap());
-            prev
-        });
-    set.evaluate(false)
-}
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        input.evaluate(true)
+    }
 
-fn main() {
-    let file = File::open("input").unwrap();
-    let lines = io::BufReader::new(file).lines().map(|l| l.unwrap());
-    println!("Part 1: {}", part1_solution(lines));
-    let file = File::open("input").unwrap();
-    let lines = io::BufReader::new(file).lines().map(|l| l.unwrap());
-    println!("Part 2: {}", part2_solution(lines));
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        input.evaluate(false)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    #[test]
-    pub fn t1() {}
+    common::puzzle_tests!(crate::Day3);
 }
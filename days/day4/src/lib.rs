@@ -0,0 +1,250 @@
+use std::{fmt::Debug, str::FromStr};
+
+use common::{ParseError, Solution};
+use geometry::{Bounds, Direction, Position};
+use grid::Grid;
+
+pub struct Board(Grid<u8>);
+
+impl Board {
+    pub fn get(&self, pos: Position) -> u8 {
+        *self.0.get(pos).expect("Cursors should stay on the board.")
+    }
+
+    pub fn width(&self) -> usize {
+        self.0.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.0.height()
+    }
+
+    pub fn get_cursor(&self, pos: Position) -> Option<Cursor> {
+        Cursor::new(pos, self.0.bounds())
+    }
+
+    fn cursors_at(&self, letter: u8) -> Vec<Cursor> {
+        self.0
+            .positions(|b| *b == letter)
+            .map(|pos| self.get_cursor(pos).unwrap())
+            .collect()
+    }
+
+    pub fn find_x(&self) -> usize {
+        let a_pos = self.cursors_at(b'A');
+
+        let iters = a_pos.iter().map(|c| c.get_x_iters(self));
+        let mut out = 0;
+        const MAS: [u8; 3] = *b"MAS";
+        for iter in iters {
+            let Some((d1, d2)) = iter else {
+                continue;
+            };
+
+            if (d1.0.eq(MAS) || d1.1.eq(MAS))
+                && (d2.0.eq(MAS) || d2.1.eq(MAS))
+            {
+                out += 1;
+            }
+        }
+        out
+    }
+
+    pub fn find(&self, term: &[u8]) -> usize {
+        let starting_cursors = self.cursors_at(term[0]);
+        let iters = starting_cursors.iter().flat_map(|i| {
+            i.get_all_iterators(self, term.len()).into_iter()
+        });
+        let mut total = 0;
+        for iter in iters {
+            if iter.eq(term.to_owned()) {
+                total += 1;
+            }
+        }
+        total
+    }
+}
+
+impl FromStr for Board {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, |_, c| Ok(c as u8)).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Cursor {
+    pos: Position,
+    bounds: Bounds,
+}
+
+impl Cursor {
+    pub fn new(pos: Position, bounds: Bounds) -> Option<Self> {
+        bounds.contains(pos).then_some(Self { pos, bounds })
+    }
+    pub fn shift(&self, direction: &Direction) -> Option<Self> {
+        Self::new(self.pos + *direction, self.bounds)
+    }
+    pub fn to_iterator<'a>(
+        self,
+        direction: Direction,
+        board: &'a Board,
+        length: usize,
+    ) -> Option<CursorIterator<'a>> {
+        CursorIterator::new(direction, self, board, length)
+    }
+
+    pub fn get_all_iterators<'a>(
+        &self,
+        board: &'a Board,
+        length: usize,
+    ) -> Vec<CursorIterator<'a>> {
+        Direction::NEIGHBOURS8
+            .into_iter()
+            .filter_map(|direction| {
+                self.to_iterator(direction, board, length)
+            })
+            .collect()
+    }
+
+    pub fn get_x_iters<'a>(
+        &self,
+        board: &'a Board,
+    ) -> Option<(
+        (CursorIterator<'a>, CursorIterator<'a>),
+        (CursorIterator<'a>, CursorIterator<'a>),
+    )> {
+        let dir1 = Direction::DOWN_RIGHT;
+        let op_dir1 = dir1.rotate_180();
+        let dir2 = Direction::UP_RIGHT;
+        let op_dir2 = dir2.rotate_180();
+        let shifted_op = self.shift(&op_dir1)?;
+        let iter1 = shifted_op.to_iterator(dir1, board, 3)?;
+        let op_iter1 =
+            self.shift(&dir1)?.to_iterator(op_dir1, board, 3)?;
+        let iter2 =
+            self.shift(&op_dir2)?.to_iterator(dir2, board, 3)?;
+        let op_iter2 =
+            self.shift(&dir2)?.to_iterator(op_dir2, board, 3)?;
+        Some(((iter1, op_iter1), (iter2, op_iter2)))
+    }
+}
+
+pub struct CursorIterator<'a> {
+    direction: Direction,
+    current: Cursor,
+    board: &'a Board,
+    left: usize,
+}
+
+impl Debug for CursorIterator<'_> {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        #[derive(Debug)]
+        struct Iter {
+            _direction: Direction,
+            _current: Cursor,
+            _left: usize,
+        }
+        let Self {
+            direction,
+            current,
+            board: _,
+            left,
+        } = self;
+
+        // per Chayim Friedman’s suggestion
+        Debug::fmt(
+            &Iter {
+                _direction: *direction,
+                _current: *current,
+                _left: *left,
+            },
+            f,
+        )
+    }
+}
+
+impl<'a> CursorIterator<'a> {
+    pub fn new(
+        direction: Direction,
+        current: Cursor,
+        board: &'a Board,
+        length: usize,
+    ) -> Option<Self> {
+        let last = current.pos + direction * (length - 1) as isize;
+        if !current.bounds.contains(last) {
+            return None;
+        }
+        Some(Self {
+            direction,
+            current,
+            board,
+            left: length,
+        })
+    }
+}
+
+impl<'a> Iterator for CursorIterator<'a> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.left == 0 {
+            None
+        } else {
+            let out = Some(self.board.get(self.current.pos));
+            self.left -= 1;
+            if self.left > 0 {
+                self.current =
+                    self.current.shift(&self.direction).expect(
+                        "Verified in bounds at Iterator creation so bounds should be properly constrained.",
+                    );
+            }
+            out
+        }
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Board;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        input.find(b"XMAS")
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        input.find_x()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geometry::{Direction, Position};
+
+    use crate::Board;
+
+    #[test]
+    pub fn t1() {
+        let board: Board = "XMAS".parse().unwrap();
+        let cursor = board.get_cursor(Position::ZERO).unwrap();
+        let iter =
+            cursor.to_iterator(Direction::RIGHT, &board, 4).unwrap();
+        for i in iter {
+            println!("{}", char::from(i));
+        }
+    }
+
+    common::puzzle_tests!(crate::Day4, part1 = 1, part2 = 1);
+}
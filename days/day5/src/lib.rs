@@ -0,0 +1,98 @@
+mod page_id;
+mod page_ids;
+mod rules;
+
+use anyhow::Context;
+use std::str::FromStr;
+
+use common::{ParseError, Solution};
+use page_ids::PageIds;
+use rules::{Rule, RulesMap};
+
+pub struct Manual {
+    rules: RulesMap,
+    updates: Vec<PageIds>,
+}
+
+impl FromStr for Manual {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().enumerate();
+        let rules: Vec<Rule> = lines
+            .by_ref()
+            .take_while(|(_, line)| !line.is_empty())
+            .map(|(i, line)| {
+                line.parse().map_err(|e: ParseError| e.on_line(i + 1))
+            })
+            .collect::<Result<_, _>>()?;
+        let updates = lines
+            .map(|(i, line)| {
+                line.parse().map_err(|e: ParseError| e.on_line(i + 1))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            rules: rules.into_iter().collect(),
+            updates,
+        })
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = Manual;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        let Manual { rules, updates } = input;
+        let mut total_middles: usize = 0;
+        for ids in updates {
+            match ids
+                .validate_against_ruleset(rules)
+                .with_context(|| format!("For update {}", ids))
+            {
+                Ok(_) => {
+                    total_middles += ids.middle_id();
+                }
+                Err(_e) => {
+                    continue;
+                }
+            }
+        }
+        total_middles
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        let Manual { rules, updates } = input;
+        let mut total_middles: usize = 0;
+        for ids in updates {
+            match ids
+                .validate_against_ruleset(rules)
+                .with_context(|| format!("For update {}", ids))
+            {
+                Ok(_) => {
+                    continue;
+                }
+                Err(_) => {
+                    let new_ids = PageIds::from_inner(
+                        rules.walk_graph(ids.as_slice()).unwrap(),
+                    );
+                    total_middles += new_ids.middle_id()
+                }
+            }
+        }
+        total_middles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::puzzle_tests!(crate::Day5);
+}
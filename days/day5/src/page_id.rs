@@ -1,4 +1,6 @@
-use std::{fmt::Display, num::ParseIntError, ops::AddAssign, str::FromStr};
+use std::{fmt::Display, ops::AddAssign, str::FromStr};
+
+use common::ParseError;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct PageId(u8);
@@ -10,10 +12,12 @@ impl Display for PageId {
 }
 
 impl FromStr for PageId {
-    type Err = ParseIntError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let num: u8 = s.parse()?;
+        let num: u8 = s.parse().map_err(|e| {
+            ParseError::at(s, 0, format!("Invalid page number {s:?}: {e}"))
+        })?;
         Ok(PageId(num))
     }
 }
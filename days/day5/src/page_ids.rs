@@ -1,5 +1,7 @@
 use std::{fmt::Display, str::FromStr};
 
+use common::ParseError;
+
 use crate::{
     page_id::PageId,
     rules::{RuleError, RulesMap},
@@ -7,15 +9,15 @@ use crate::{
 
 pub struct PageIds(Vec<PageId>);
 
-impl Into<Vec<PageId>> for PageIds {
-    fn into(self) -> Vec<PageId> {
-        self.0
+impl From<PageIds> for Vec<PageId> {
+    fn from(value: PageIds) -> Self {
+        value.0
     }
 }
 
 impl PageIds {
-    pub fn into_inner(self) -> Vec<PageId> {
-        self.into()
+    pub fn as_slice(&self) -> &[PageId] {
+        &self.0
     }
 
     pub fn from_inner(ids: Vec<PageId>) -> Self {
@@ -32,13 +34,13 @@ impl Display for PageIds {
 }
 
 impl FromStr for PageIds {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let nums = s.split(",");
         let mut inner: Vec<PageId> = Vec::new();
         for num in nums {
-            inner.push(num.parse()?);
+            inner.push(num.parse().map_err(|e: ParseError| e.within(s, num))?);
         }
         Ok(Self(inner))
     }
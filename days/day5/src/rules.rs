@@ -5,7 +5,7 @@ use std::{
     str::FromStr,
 };
 
-use anyhow::anyhow;
+use common::ParseError;
 use lazy_static::lazy_static;
 
 use crate::page_id::PageId;
@@ -43,7 +43,7 @@ impl PartialOrd for Rules {
         if other_is_after {
             return Some(Ordering::Greater);
         }
-        return Some(Ordering::Equal);
+        Some(Ordering::Equal)
     }
 }
 
@@ -175,22 +175,23 @@ lazy_static! {
 }
 
 impl FromStr for Rule {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let c = RULE_REGEX
-            .captures(s)
-            .ok_or(anyhow!("Couldn't match rule regex."))?;
-        let before = c
-            .name("before")
-            .expect("Should be before match since regex matched.");
-        let after = c
-            .name("after")
-            .expect("Should be after match since regex matched.");
+        let c = RULE_REGEX.captures(s).ok_or_else(|| {
+            ParseError::at(s, 0, "Expected a rule like 47|53.")
+        })?;
+        let page = |name| {
+            let page = c
+                .name(name)
+                .expect("Both pages are matched whenever the regex is.")
+                .as_str();
+            page.parse().map_err(|e: ParseError| e.within(s, page))
+        };
 
         Ok(Self {
-            before: before.as_str().parse().expect("should parse"),
-            after: after.as_str().parse().expect("should parse"),
+            before: page("before")?,
+            after: page("after")?,
         })
     }
 }
@@ -256,7 +257,7 @@ impl RulesMap {
             };
             //         remove edge e from the graph
             ids_left.remove(&id);
-            let outgoing_edges = rule.intersection_with_afters(&ids_left);
+            let outgoing_edges = rule.intersection_with_afters(ids_left);
             //     for each node m with an edge e from n to m do
             for m in outgoing_edges {
                 let Some(rule) = self.get_rule(*m) else {
@@ -285,23 +286,12 @@ impl RulesMap {
             };
             !rule.has_befores() || rule.assert_none_are_before(ids).is_ok()
         });
-        let mut ids_left: HashSet<PageId, _> =
-            ids.iter().map(|id| *id).collect();
-        let ids_without_incoming = without_befores.map(|id| *id).collect();
+        let mut ids_left: HashSet<PageId, _> = ids.iter().copied().collect();
+        let ids_without_incoming = without_befores.copied().collect();
         self.walk_graph_inner(&mut ids_left, ids_without_incoming)
     }
 }
 
-impl FromIterator<String> for RulesMap {
-    fn from_iter<T: IntoIterator<Item = String>>(iter: T) -> Self {
-        let iter = iter.into_iter();
-        let rules =
-            iter.map(|rule| rule.parse::<Rule>().expect("Should parse."));
-        let out: Self = Self::from_iter(rules);
-        out
-    }
-}
-
 impl FromIterator<Rule> for RulesMap {
     fn from_iter<T: IntoIterator<Item = Rule>>(iter: T) -> Self {
         let mut out: HashMap<PageId, Rules> = HashMap::new();
@@ -1,23 +1,30 @@
-use std::{collections::HashSet, fmt::Display};
+use std::{fmt::Display, str::FromStr};
 
-use crate::{
-    direction::Direction, guard::Guard, position::Position, tile::Tile,
-};
+use common::ParseError;
+use geometry::{Bounds, Direction, Position};
+use grid::Grid;
 
+use crate::{guard::Guard, tile::Tile};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Obstacle,
+}
+
+#[derive(Clone)]
 pub struct Board {
     guard: Option<Guard>,
-    obstacles: HashSet<Position>,
-    width: usize,
-    height: usize,
+    map: Grid<Cell>,
 }
 
 impl Display for Board {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in 0..(self.height as isize) {
+        for y in 0..(self.height() as isize) {
             if y != 0 {
-                writeln!(f, "")?;
+                writeln!(f)?;
             }
-            for x in 0..(self.width as isize) {
+            for x in 0..(self.width() as isize) {
                 let pos = Position::new(x, y);
                 let at = self.get(pos).unwrap();
                 let c: char = match at {
@@ -50,19 +57,7 @@ pub enum BoardState {
 
 impl Board {
     pub fn get(&self, pos: Position) -> Option<BoardState> {
-        if pos.x() >= self.width as isize {
-            return None;
-        }
-        if pos.y() >= self.height as isize {
-            return None;
-        }
-        if pos.x() < 0 {
-            return None;
-        }
-
-        if pos.y() < 0 {
-            return None;
-        }
+        let cell = self.map.get(pos)?;
 
         if let Some(guard) = &self.guard {
             if let Some(tile) = guard.visited(pos) {
@@ -72,10 +67,10 @@ impl Board {
                 return Some(BoardState::Guard);
             }
         }
-        if let Some(_obstacle) = self.obstacles.get(&pos) {
+        if *cell == Cell::Obstacle {
             return Some(BoardState::Obstacle);
         }
-        return Some(BoardState::Empty);
+        Some(BoardState::Empty)
     }
     pub fn take_guard(&mut self) -> Guard {
         self.guard
@@ -84,37 +79,43 @@ impl Board {
     }
 
     pub fn width(&self) -> usize {
-        self.width
+        self.map.width()
     }
 
     pub fn height(&self) -> usize {
-        self.height
+        self.map.height()
     }
 
-    pub fn cropped_board(&self, bounds: (Position, Position)) -> Self {
-        let (min, max) = bounds;
-        let size = max - min;
+    /// Crops the board down to `bounds`, clamped to the edges of the board.
+    pub fn cropped_board(&self, bounds: Bounds) -> Self {
+        let bounds = bounds
+            .intersection(self.map.bounds())
+            .expect("Bounds should overlap the board.");
         let new_guard = self
             .guard
             .as_ref()
             .map(|guard| guard.crop_guard_visits(bounds));
-        let obstacles: HashSet<Position> =
-            self.obstacles.iter().map(|pos| *pos - min).collect();
-        let out = Self {
+        let map = self
+            .map
+            .view(bounds)
+            .expect("Bounds were clamped to the board.")
+            .to_grid();
+        Self {
             guard: new_guard,
-            obstacles,
-            width: size.x() as usize,
-            height: size.y() as usize,
-        };
-        out
+            map,
+        }
     }
 
     pub fn add_obstacle(&mut self, pos: Position) {
-        self.obstacles.insert(pos);
+        if let Some(cell) = self.map.get_mut(pos) {
+            *cell = Cell::Obstacle;
+        }
     }
 
     pub fn remove_obstacle(&mut self, pos: Position) {
-        self.obstacles.remove(&pos);
+        if let Some(cell) = self.map.get_mut(pos) {
+            *cell = Cell::Empty;
+        }
     }
 
     pub fn set_guard(&mut self, guard: Guard) {
@@ -122,46 +123,29 @@ impl Board {
     }
 }
 
-impl FromIterator<String> for Board {
-    fn from_iter<T: IntoIterator<Item = String>>(iter: T) -> Self {
-        let mut obstacles = HashSet::new();
+impl FromStr for Board {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut guard = None;
-        let mut handle_line = |y: usize, line: String| {
-            for (x, byte) in line.as_bytes().iter().enumerate() {
-                match byte {
-                    b'^' => {
-                        let pos = Position::new(x as isize, y as isize);
-                        if guard.is_some() {
-                            panic!("Multiple guards detected.");
-                        }
-                        guard = Some(Guard::new(pos));
-                    }
-                    b'.' => {}
-                    b'#' => {
-                        let pos = Position::new(x as isize, y as isize);
-                        obstacles.insert(pos);
-                    }
-                    byte => {
-                        panic!("unsupported character {}", *byte as char)
-                    }
+        let map = Grid::parse(s, |pos, c| match c {
+            '^' => {
+                if guard.is_some() {
+                    return Err("Multiple guards detected.".into());
                 }
+                guard = Some(Guard::new(pos));
+                Ok(Cell::Empty)
             }
-        };
-        let mut iter = iter.into_iter().peekable();
-        let mut height = 0;
-        let width = iter.peek().unwrap().len();
-        for line in iter {
-            handle_line(height, line);
-            height += 1;
-        }
+            '.' => Ok(Cell::Empty),
+            '#' => Ok(Cell::Obstacle),
+            c => Err(format!("Unsupported character {c:?}.")),
+        })?;
         let Some(guard) = guard else {
-            panic!("Guard not found.")
+            return Err(ParseError::new("Guard not found."));
         };
-        Self {
-            width,
-            height,
+        Ok(Self {
             guard: Some(guard),
-            obstacles,
-        }
+            map,
+        })
     }
 }
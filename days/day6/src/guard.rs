@@ -1,11 +1,11 @@
 use std::collections::HashMap;
-use std::{isize, usize};
+
+use geometry::{Bounds, Direction, Position};
 
 use crate::board::{Board, BoardState};
-use crate::direction::Direction;
-use crate::position::Position;
 use crate::tile::Tile;
 
+#[derive(Clone)]
 pub struct Guard {
     pos: Position,
     visited: HashMap<Position, Tile>,
@@ -38,34 +38,14 @@ impl Guard {
         }
     }
 
-    pub fn get_guard_bounds(&self) -> (Position, Position) {
-        let mut min_x = isize::MAX;
-        let mut max_x = 0;
-        let mut min_y = isize::MAX;
-        let mut max_y: isize = 0;
-        for (pos, _) in self.visited.iter() {
-            let x = pos.x();
-            let y = pos.y();
-            if x < min_x {
-                min_x = x;
-            }
-            if x > max_x {
-                max_x = x;
-            }
-            if y < min_y {
-                min_y = y;
-            }
-            if y > max_y {
-                max_y = y;
-            }
-        }
-        (
-            Position::new(min_x - 1, min_y - 1),
-            Position::new(max_x + 2, max_y + 2),
-        )
+    /// The bounds of every visited position with a one tile margin.
+    pub fn get_guard_bounds(&self) -> Bounds {
+        Bounds::enclosing(self.visited.keys().copied())
+            .expect("Guard always visits its starting position.")
+            .expanded(1)
     }
-    pub fn crop_guard_visits(&self, bounds: (Position, Position)) -> Self {
-        let (min, _max) = bounds;
+    pub fn crop_guard_visits(&self, bounds: Bounds) -> Self {
+        let min = bounds.min();
         let mut out = Self::new(self.pos() - min);
         for (pos, tile) in self.visited.iter() {
             let mut tile = *tile;
@@ -88,7 +68,7 @@ impl Guard {
         &mut self,
         board: &mut Board,
     ) -> Result<Option<Position>, Error> {
-        let next = self.direction.step_pos(self.pos);
+        let next = self.pos + self.direction;
         let Some(next_state) = board.get(next) else {
             return Err(Error::OffMap);
         };
@@ -98,7 +78,7 @@ impl Guard {
                 panic!("Shouldn't be another guard.");
             }
             BoardState::Obstacle => {
-                self.direction = self.direction.rotate_right_90();
+                self.direction = self.direction.rotate_right();
                 let mut tile = *self.visited.get(&self.pos).unwrap();
                 tile.visit(self.direction);
                 self.visited.insert(self.pos, tile);
@@ -110,8 +90,7 @@ impl Guard {
                     .visited
                     .get(&self.pos)
                     .copied()
-                    .or(Some(self.pos.into()))
-                    .unwrap();
+                    .unwrap_or(self.pos.into());
                 if tile.visited(self.direction) {
                     return Err(Error::Loop);
                 }
@@ -0,0 +1,84 @@
+mod board;
+mod guard;
+mod tile;
+
+pub use board::Board;
+use common::{ParseError, Solution};
+use guard::{Error, Guard};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = Board;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        let mut board: Board = input.clone();
+        let mut guard: Guard = board.take_guard();
+        let _e = guard.simulate(&mut board);
+        let all_pos_visited: usize = guard.num_visited();
+        if log::log_enabled!(log::Level::Debug) {
+            board.set_guard(guard);
+            log::debug!("Visited:\n{board}");
+        }
+        all_pos_visited
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        let mut board: Board = input.clone();
+        let mut guard: Guard = board.take_guard();
+        let _e = guard.simulate(&mut board);
+        let (visited, mut reset) = guard.into();
+        let original_pos = reset.pos();
+        let mut loop_ct = 0;
+        for (pos, _tile) in visited.iter() {
+            if *pos == original_pos {
+                continue;
+            }
+            // println!("Simulated {}/{}", i, visited.len());
+            board.add_obstacle(*pos);
+            if let Error::Loop = reset.simulate(&mut board) {
+                loop_ct += 1;
+                // Redoing the loop to draw it is slow, so it's only done when
+                // someone's looking.
+                if log::log_enabled!(log::Level::Trace) {
+                    let old_pos = reset.pos();
+                    let old_direction = reset.direction();
+                    let mut new_guard = Guard::new(old_pos);
+                    new_guard.set_direction(old_direction);
+                    new_guard.simulate(&mut board);
+                    let bounds = new_guard.get_guard_bounds();
+                    board.set_guard(new_guard);
+                    let cropped = board.cropped_board(bounds);
+                    if cropped.width() * cropped.height() < 64 {
+                        log::trace!("Loop at {}:\n{cropped}", bounds.min());
+                    }
+                    let _ = board.take_guard();
+                }
+            }
+            reset = reset.reset();
+            board.remove_obstacle(*pos);
+        }
+        loop_ct
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Board;
+
+    #[test]
+    pub fn parse_errors() {
+        let error = "..^\n^..".parse::<Board>().err().unwrap();
+        assert_eq!((error.line(), error.column()), (Some(2), Some(1)));
+        assert!("...\n...".parse::<Board>().is_err());
+    }
+
+    common::puzzle_tests!(crate::Day6, part2 = 0);
+}
@@ -1,8 +1,7 @@
 use std::hash::Hash;
 
 use bitflags::bitflags;
-
-use crate::{direction::Direction, position::Position};
+use geometry::{Direction, Position};
 
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -45,7 +44,7 @@ impl Tile {
         self.pos = pos;
     }
 
-    pub fn to_char(&self) -> char {
+    pub fn to_char(self) -> char {
         let vertical = self
             .visited_from
             .intersects(DirectionFlags::DOWN | DirectionFlags::UP);
@@ -54,30 +53,8 @@ impl Tile {
             .intersects(DirectionFlags::LEFT | DirectionFlags::RIGHT);
         match (horizontal, vertical) {
             (true, true) => '+',
-            (true, false) => {
-                let both = DirectionFlags::LEFT & DirectionFlags::RIGHT;
-                if self.visited_from == both {
-                    '-'
-                } else if self.visited_from.contains(DirectionFlags::LEFT) {
-                    '-'
-                } else if self.visited_from.contains(DirectionFlags::RIGHT) {
-                    '-'
-                } else {
-                    unreachable!()
-                }
-            }
-            (false, true) => {
-                let both = DirectionFlags::UP & DirectionFlags::DOWN;
-                if self.visited_from == both {
-                    '|'
-                } else if self.visited_from.contains(DirectionFlags::UP) {
-                    '|'
-                } else if self.visited_from.contains(DirectionFlags::DOWN) {
-                    '|'
-                } else {
-                    unreachable!()
-                }
-            }
+            (true, false) => '-',
+            (false, true) => '|',
             (false, false) => panic!("Should be visited at least once!"),
         }
     }
@@ -108,7 +85,7 @@ impl From<Position> for Tile {
 
 #[cfg(test)]
 mod tests {
-    use crate::{direction::Direction, position::Position};
+    use geometry::{Direction, Position};
 
     use super::Tile;
 
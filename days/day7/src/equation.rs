@@ -1,13 +1,14 @@
 use std::{
     fmt::{Display, Write},
-    num::ParseIntError,
     str::FromStr,
 };
 
+use common::{parse_field, ParseError};
+
+#[derive(Clone)]
 pub struct Equation {
     result: usize,
     values: Vec<usize>,
-    dbg_str: String,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -59,9 +60,9 @@ impl<'a> Iterator for OperatorPermutationIterator<'a> {
             .iter()
             .map(|ind| self.operators[*ind])
             .collect();
-        let mut iter = self.operation_indicies.iter_mut().enumerate();
+        let iter = self.operation_indicies.iter_mut().enumerate();
         let mut got_to: Option<usize> = None;
-        while let Some((i, ind)) = iter.next() {
+        for (i, ind) in iter {
             if *ind != self.operators.len() - 1 {
                 *ind += 1;
                 got_to = Some(i);
@@ -88,31 +89,22 @@ impl<'a> Iterator for OperatorPermutationIterator<'a> {
 }
 
 impl Equation {
-    pub fn is_solvable_with(&mut self, ops: &[Ops]) -> bool {
+    pub fn is_solvable_with(&self, ops: &[Ops]) -> bool {
         let ops_iter = OperatorPermutationIterator::new(self.values.len(), ops);
         for op in ops_iter {
             if self.check_if_solvable_with_ops(op) {
                 return true;
             }
         }
-        return false;
+        false
     }
     pub fn result(&self) -> usize {
         self.result
     }
-    fn check_if_solvable_with_ops(&mut self, op: Vec<Ops>) -> bool {
+    fn check_if_solvable_with_ops(&self, op: Vec<Ops>) -> bool {
         let mut value_iter = self.values.iter();
         let mut running = *value_iter.next().unwrap();
-        let mut full_eq = &mut self.dbg_str;
-        full_eq.clear();
-        if cfg!(debug_assertions) {
-            write!(&mut full_eq, "{} == ", self.result).unwrap();
-            write!(&mut full_eq, "{running} ").unwrap();
-        }
         for (val, op) in value_iter.zip(op.iter()) {
-            if cfg!(debug_assertions) {
-                write!(&mut full_eq, "{op} {val} ").unwrap();
-            }
             match op {
                 Ops::Add => {
                     running += *val;
@@ -121,34 +113,48 @@ impl Equation {
                     running *= *val;
                 }
                 Ops::Concat => {
-                    let val_log = val.checked_ilog10().or(Some(0)).unwrap() + 1;
+                    let val_log = val.checked_ilog10().unwrap_or(0) + 1;
                     let times_ten = 10usize.pow(val_log);
                     running = running * times_ten + val;
                 }
             };
         }
         let out = running == self.result;
-        if out && cfg!(debug_assertions) {
-            println!("{full_eq}");
+        if out {
+            log::trace!("{}", self.with_ops(&op));
+        }
+        out
+    }
+
+    /// The equation written out with `ops` between its values.
+    fn with_ops(&self, ops: &[Ops]) -> String {
+        let mut out = format!("{} == {}", self.result, self.values[0]);
+        for (val, op) in self.values[1..].iter().zip(ops) {
+            write!(out, " {op} {val}").unwrap();
         }
         out
     }
 }
 
 impl FromStr for Equation {
-    type Err = ParseIntError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (result, nums) = s.split_once(':').unwrap();
-        let nums = nums.split(' ');
-        let values: Result<Vec<usize>, _> = nums
+        let (result, nums) = s.split_once(':').ok_or_else(|| {
+            ParseError::at(s, 0, "Expected a result followed by ':'.")
+        })?;
+        let values: Vec<usize> = nums
+            .split(' ')
             .filter(|num| !num.is_empty())
-            .map(|num| num.parse())
-            .collect();
+            .map(|num| parse_field(s, num))
+            .collect::<Result<_, _>>()?;
+        if values.is_empty() {
+            let end = s.chars().count();
+            return Err(ParseError::at(s, end, "Expected at least one value."));
+        }
         Ok(Self {
-            result: result.parse()?,
-            values: values?,
-            dbg_str: String::with_capacity(1024),
+            result: parse_field(s, result)?,
+            values,
         })
     }
 }
@@ -168,7 +174,7 @@ mod tests {
     #[test]
     pub fn eq_test() {
         fn eq_assert(string: &str, solvable: bool) {
-            let mut eq: Equation = string.parse().unwrap();
+            let eq: Equation = string.parse().unwrap();
             assert!(eq.is_solvable_with(&P1_OPS) == solvable);
         }
         eq_assert("190: 10 19", true);
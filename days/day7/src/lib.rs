@@ -0,0 +1,45 @@
+mod equation;
+
+use common::{parse_lines, ParseError, Solution};
+pub use equation::Equation;
+use equation::Ops;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Vec<Equation>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        let mut out = 0;
+        let ops = vec![Ops::Add, Ops::Mul];
+        for eq in input {
+            if eq.is_solvable_with(&ops) {
+                out += eq.result();
+            }
+        }
+        out
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        let mut out = 0;
+        let ops = vec![Ops::Add, Ops::Mul, Ops::Concat];
+        for eq in input {
+            if eq.is_solvable_with(&ops) {
+                out += eq.result();
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::puzzle_tests!(crate::Day7);
+}
@@ -0,0 +1,190 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::str::FromStr;
+
+use common::{ParseError, Solution};
+use geometry::{Bounds, Position};
+use grid::Grid;
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Frequency(char);
+
+impl Frequency {
+    pub fn new(frequency: char) -> Self {
+        Self(frequency)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Antenna {
+    frequency: Frequency,
+    pos: Position,
+}
+
+impl Antenna {
+    pub fn new(freq: Frequency, pos: Position) -> Self {
+        Self {
+            frequency: freq,
+            pos,
+        }
+    }
+
+    pub fn char(&self) -> char {
+        self.frequency.0
+    }
+
+    pub fn pos(&self) -> Position {
+        self.pos
+    }
+
+    pub fn get_antinodes_with(
+        &self,
+        other: Antenna,
+        bounds: Option<Bounds>,
+    ) -> HashSet<Position> {
+        let diff = self.pos - other.pos;
+        let mut out = HashSet::with_capacity(2);
+        let Some(bounds) = bounds else {
+            out.insert(self.pos + diff);
+            out.insert(other.pos - diff);
+            return out;
+        };
+        // Every grid point on the line counts, not just multiples of the gap
+        // between the antennas.
+        let diff = diff.reduced();
+        let mut pos = self.pos;
+        while bounds.contains(pos) {
+            out.insert(pos);
+            pos += diff;
+        }
+        let mut pos = other.pos;
+        while bounds.contains(pos) {
+            out.insert(pos);
+            pos -= diff;
+        }
+        out
+    }
+}
+
+pub struct AntennaSet(Vec<Antenna>);
+
+impl AntennaSet {
+    pub fn add(&mut self, antenna: Antenna) {
+        self.0.push(antenna);
+    }
+
+    pub fn get_antinodes(&self, bounds: Option<Bounds>) -> HashSet<Position> {
+        let mut out = HashSet::new();
+        for (i, a) in self.0.iter().enumerate() {
+            for b in &self.0[i + 1..] {
+                out.extend(a.get_antinodes_with(*b, bounds));
+            }
+        }
+        out
+    }
+}
+
+impl From<Vec<Antenna>> for AntennaSet {
+    fn from(value: Vec<Antenna>) -> Self {
+        Self(value)
+    }
+}
+
+pub struct City {
+    map: Grid<char>,
+    antennas: HashMap<Frequency, AntennaSet>,
+}
+
+impl Display for City {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut out = self.map.clone();
+        for pos in self.get_all_antinodes(f.alternate()) {
+            if let Some(c) = out.get_mut(pos) {
+                *c = '#';
+            }
+        }
+        writeln!(f, "{out}")
+    }
+}
+
+impl City {
+    pub fn width(&self) -> usize {
+        self.map.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.map.height()
+    }
+
+    pub fn count_antinodes(&self, part2: bool) -> usize {
+        self.get_all_antinodes(part2).len()
+    }
+
+    pub fn get_all_antinodes(&self, part2: bool) -> HashSet<Position> {
+        let mut combined: HashSet<Position> = HashSet::new();
+        for antenna_set in self.antennas.values() {
+            let bounds = part2.then(|| self.map.bounds());
+            let antinodes = antenna_set.get_antinodes(bounds);
+            if bounds.is_some() {
+                combined.extend(antinodes);
+            } else {
+                combined
+                    .extend(antinodes.iter().filter(|p| self.map.contains(**p)))
+            }
+        }
+        combined
+    }
+}
+
+impl FromStr for City {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut antennas: HashMap<Frequency, AntennaSet> = HashMap::new();
+        let map = Grid::parse(s, |pos, c| {
+            if c == '.' {
+                return Ok(c);
+            }
+            if !c.is_ascii_alphanumeric() {
+                return Err(format!("{c:?} isn't a letter or digit."));
+            }
+            let freq = Frequency::new(c);
+            let antenna = Antenna::new(freq, pos);
+            antennas
+                .entry(freq)
+                .and_modify(|v| v.add(antenna))
+                .or_insert(vec![antenna].into());
+            Ok(c)
+        })?;
+        Ok(Self { map, antennas })
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = City;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        log::debug!("Antinodes:\n{input}");
+        input.count_antinodes(false)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        log::debug!("w: {} h: {}", input.width(), input.height());
+        log::debug!("Antinodes with harmonics:\n{input:#}");
+        input.count_antinodes(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::puzzle_tests!(crate::Day8, part2 = 0);
+}
@@ -1,9 +1,9 @@
 use std::fmt::Display;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::num::{NonZeroUsize, ParseIntError};
+use std::num::NonZeroUsize;
 use std::str::FromStr;
 
+use common::{ParseError, Solution};
+
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct FileId(NonZeroUsize);
@@ -29,6 +29,7 @@ impl From<FileId> for usize {
     }
 }
 
+#[derive(Clone)]
 pub struct Disk {
     blocks: Vec<Option<FileId>>,
     current_file_id: FileId,
@@ -60,7 +61,7 @@ impl Disk {
 
     pub fn fill(&mut self, len: usize, is_file: bool) {
         self.blocks
-            .extend((0..len).map(|_| is_file.then(|| self.current_file_id)));
+            .extend((0..len).map(|_| is_file.then_some(self.current_file_id)));
         if is_file {
             self.current_file_id = self.current_file_id.next_id();
         }
@@ -169,8 +170,7 @@ impl Disk {
         for (ind, _) in contig_file.iter() {
             self.blocks[*ind] = None;
         }
-        return (self.current_file_id != FileId::ZERO)
-            .then_some(end_of_contig_file);
+        (self.current_file_id != FileId::ZERO).then_some(end_of_contig_file)
     }
 
     pub fn compact_without_fragmentation(&mut self) {
@@ -203,13 +203,17 @@ impl Disk {
 }
 
 impl FromStr for Disk {
-    type Err = ParseIntError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut lengths = Vec::new();
         let mut total_len: usize = 0;
-        for c in s.as_bytes() {
-            let len = c - b'0';
+        // Tolerate the line ending of a map saved on Windows.
+        let map = s.trim_end_matches(['\r', '\n']);
+        for (i, c) in map.chars().enumerate() {
+            let len = c.to_digit(10).ok_or_else(|| {
+                ParseError::at(s, i, format!("{c:?} isn't a digit."))
+            })? as u8;
             lengths.push(len);
             total_len += len as usize;
         }
@@ -229,31 +233,47 @@ impl FromStr for Disk {
     }
 }
 
-pub fn part1_solution(mut lines: impl Iterator<Item = String>) -> impl Display {
-    let mut disk: Disk = lines.next().unwrap().parse().unwrap();
-    disk.compact();
-    // println!("{disk}");
-    disk.hash()
-}
+pub struct Day9;
 
-pub fn part2_solution(mut lines: impl Iterator<Item = String>) -> impl Display {
-    let mut disk: Disk = lines.next().unwrap().parse().unwrap();
-    disk.compact_without_fragmentation();
-    // println!("{disk}");
-    disk.hash()
-}
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Disk;
+    type Part1 = usize;
+    type Part2 = usize;
 
-fn main() {
-    let file = File::open("input").unwrap();
-    let lines = io::BufReader::new(file).lines().map(|l| l.unwrap());
-    println!("Part 1: {}", part1_solution(lines));
-    let file = File::open("input").unwrap();
-    let lines = io::BufReader::new(file).lines().map(|l| l.unwrap());
-    println!("Part 2: {}", part2_solution(lines));
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = input
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::new("Expected a disk map."))?;
+        line.parse().map_err(|e: ParseError| e.on_line(1))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        let mut disk = input.clone();
+        disk.compact();
+        // println!("{disk}");
+        disk.hash()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        let mut disk = input.clone();
+        disk.compact_without_fragmentation();
+        // println!("{disk}");
+        disk.hash()
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::Disk;
+
     #[test]
-    pub fn t1() {}
+    pub fn parse_errors() {
+        assert!("12345\r".parse::<Disk>().is_ok());
+        let error = "12x45".parse::<Disk>().err().unwrap();
+        assert_eq!(error.column(), Some(3));
+    }
+
+    common::puzzle_tests!(crate::Day9, part2 = 0);
 }
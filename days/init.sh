@@ -1,16 +1,10 @@
 SCRIPT_DIR=$( cd -- "$( dirname -- "${BASH_SOURCE[0]}" )" &> /dev/null && pwd )
-if ! command -v "aoc"
-then
-echo "Error: aoc-cli could not be found. Install the aoc-cli by running:\n\$ cargo install aoc-cli"
-exit 1
-fi
-if ! command -v "cargo-generate"
-then
-echo "Error: aoc-cli could not be found. Install cargo-generate by running:\n\$ cargo install cargo-generate"
-exit 1
-fi
+# Where .aoc.toml keeps the session, relative to the workspace root.
+SESSION_FILE=$(cd "$SCRIPT_DIR" && cargo run -q -p aoc -- config session_file)
+case "$SESSION_FILE" in
+/*) ;;
+*) SESSION_FILE="$SCRIPT_DIR/../$SESSION_FILE" ;;
+esac
 read -n 128 -p "Paste your Advent of Code session cookie here: " -s varcookie
-echo "$varcookie" > "$SCRIPT_DIR/.adventofcode.session"
+echo "$varcookie" > "$SESSION_FILE"
 chmod +x "$SCRIPT_DIR/day"
-chmod +x "$SCRIPT_DIR/template/init.sh"
-chmod +x "$SCRIPT_DIR/template/submit.liquid"
\ No newline at end of file
@@ -0,0 +1,27 @@
+use common::{ParseError, Solution};
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    const DAY: u8 = {{day}};
+    type Input = ();
+    type Part1 = &'static str;
+    type Part2 = &'static str;
+
+    fn parse(_input: &str) -> Result<Self::Input, ParseError> {
+        Ok(())
+    }
+
+    fn part1(_input: &Self::Input) -> Self::Part1 {
+        "todo"
+    }
+
+    fn part2(_input: &Self::Input) -> Self::Part2 {
+        "todo"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::puzzle_tests!(crate::Day{{day}});
+}